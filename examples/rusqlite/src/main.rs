@@ -0,0 +1,86 @@
+use kosame::prelude::*;
+
+mod schema {
+    kosame::table! {
+        // The driver attribute decides which database client the generated code targets. Tables,
+        // queries and statements for different drivers can live side by side in the same binary.
+        #![kosame(driver = "rusqlite")]
+
+        create table posts (
+            id int primary key,
+            title text not null,
+            content text,
+        );
+    }
+
+    kosame::table! {
+        #![kosame(driver = "rusqlite")]
+
+        create table comments (
+            id int primary key,
+            post_id int not null,
+            content text not null,
+            upvotes int not null default 0,
+        );
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut connection = rusqlite::Connection::open_in_memory()?;
+    connection.execute_batch(
+        "create table posts (id integer primary key, title text not null, content text);
+        create table comments (
+            id integer primary key,
+            post_id integer not null,
+            content text not null,
+            upvotes integer not null default 0
+        );",
+    )?;
+
+    kosame::statement! {
+        #![kosame(driver = "rusqlite")]
+        insert into schema::posts
+        values
+            (0, "my post", "hi, this is a post"),
+            (1, "another post", "very interesting content"),
+            (2, "post without content", null),
+    }
+    .exec_sync(&mut connection)?;
+    kosame::statement! {
+        #![kosame(driver = "rusqlite")]
+        insert into schema::comments
+        values
+            (0, 2, "wow very insightful", 0),
+            (1, 1, "nice", 0),
+            (2, 1, "didn't read lol", 3),
+    }
+    .exec_sync(&mut connection)?;
+
+    let min_upvotes = 1;
+    let rows = kosame::statement! {
+        #![kosame(driver = "rusqlite")]
+        select
+            posts.title,
+            comments.content,
+            comments.upvotes,
+        from
+            schema::posts
+            inner join schema::comments on posts.id = comments.post_id
+        where
+            comments.upvotes >= :min_upvotes
+        order by
+            comments.upvotes desc
+    }
+    .query_vec_sync(&mut connection)?;
+
+    println!("{rows:#?}");
+    // [
+    //     Row {
+    //         title: "another post",
+    //         content: "didn't read lol",
+    //         upvotes: 3,
+    //     },
+    // ]
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use crate::driver::{Connection, Transactional};
+
+// Pooled connections delegate to the connection type of their manager, so any manager whose
+// connections implement `Connection` works with kosame, e.g. `bb8_postgres`.
+impl<M> Connection for bb8::PooledConnection<'_, M>
+where
+    M: bb8::ManageConnection,
+    M::Connection: Connection,
+{
+    type Dialect = <M::Connection as Connection>::Dialect;
+    type Params<'a> = <M::Connection as Connection>::Params<'a>;
+    type Row = <M::Connection as Connection>::Row;
+    type Error = <M::Connection as Connection>::Error;
+    type RowStream<'a>
+        = <M::Connection as Connection>::RowStream<'a>
+    where
+        Self: 'a;
+
+    fn exec(
+        &mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> impl Future<Output = Result<u64, Self::Error>> + Send {
+        (**self).exec(sql, params)
+    }
+
+    fn query(
+        &mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> impl Future<Output = Result<Vec<Self::Row>, Self::Error>> + Send {
+        (**self).query(sql, params)
+    }
+
+    fn query_stream<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> impl Future<Output = Result<Self::RowStream<'a>, Self::Error>> + Send {
+        (**self).query_stream(sql, params)
+    }
+}
+
+impl<M> Transactional for bb8::PooledConnection<'_, M>
+where
+    M: bb8::ManageConnection,
+    M::Connection: Transactional,
+{
+    type Transaction<'a>
+        = <M::Connection as Transactional>::Transaction<'a>
+    where
+        Self: 'a;
+
+    fn begin(&mut self) -> impl Future<Output = Result<Self::Transaction<'_>, Self::Error>> + Send {
+        (**self).begin()
+    }
+}
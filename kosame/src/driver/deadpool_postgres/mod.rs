@@ -0,0 +1,49 @@
+use crate::driver::{Connection, Transactional};
+
+// Pooled clients dereference to `tokio_postgres::Client`, so they delegate to its impls.
+impl Connection for deadpool_postgres::Object {
+    type Dialect = kosame_sql::postgres::Dialect;
+    type Params<'a> = Vec<&'a (dyn postgres_types::ToSql + std::marker::Sync + 'a)>;
+    type Row = tokio_postgres::Row;
+    type Error = tokio_postgres::Error;
+    type RowStream<'a>
+        = tokio_postgres::RowStream
+    where
+        Self: 'a;
+
+    fn exec(
+        &mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> impl Future<Output = Result<u64, Self::Error>> + Send {
+        client(self).exec(sql, params)
+    }
+
+    fn query(
+        &mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> impl Future<Output = Result<Vec<Self::Row>, Self::Error>> + Send {
+        client(self).query(sql, params)
+    }
+
+    fn query_stream<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> impl Future<Output = Result<Self::RowStream<'a>, Self::Error>> + Send {
+        client(self).query_stream(sql, params)
+    }
+}
+
+impl Transactional for deadpool_postgres::Object {
+    type Transaction<'a> = tokio_postgres::Transaction<'a>;
+
+    fn begin(&mut self) -> impl Future<Output = Result<Self::Transaction<'_>, Self::Error>> + Send {
+        client(self).begin()
+    }
+}
+
+fn client(object: &mut deadpool_postgres::Object) -> &mut tokio_postgres::Client {
+    object
+}
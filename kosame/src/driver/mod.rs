@@ -8,11 +8,42 @@ pub mod tokio_postgres;
 #[doc(hidden)]
 pub mod postgres_types;
 
+#[cfg(feature = "rusqlite")]
+pub mod rusqlite;
+
+#[cfg(feature = "mysql")]
+pub mod mysql;
+
+#[cfg(feature = "mysql-async")]
+pub mod mysql_async;
+
+#[cfg(any(feature = "mysql", feature = "mysql-async"))]
+#[doc(hidden)]
+pub mod mysql_types;
+
+#[cfg(feature = "deadpool-postgres")]
+mod deadpool_postgres;
+
+#[cfg(feature = "bb8")]
+mod bb8;
+
+mod transaction;
+
+pub use transaction::*;
+
+use futures_core::Stream;
+
+use crate::stream::IterStream;
+
 pub trait Connection {
     type Dialect: kosame_sql::Dialect;
     type Params<'a>;
     type Row;
-    type Error: std::error::Error + 'static;
+    type Error: std::error::Error + Send + Sync + 'static;
+    /// The stream returned by [`Connection::query_stream`].
+    type RowStream<'a>: Stream<Item = Result<Self::Row, Self::Error>>
+    where
+        Self: 'a;
 
     fn exec(
         &mut self,
@@ -25,4 +56,22 @@ pub trait Connection {
         sql: &str,
         params: &Self::Params<'_>,
     ) -> impl Future<Output = Result<Vec<Self::Row>, Self::Error>> + Send;
+
+    /// Like [`Connection::query`], but yields the rows one by one instead of collecting them
+    /// first. Drivers without native support stream the collected rows, see [`CollectedRows`].
+    fn query_stream<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> impl Future<Output = Result<Self::RowStream<'a>, Self::Error>> + Send;
+}
+
+/// The row stream of drivers that cannot stream rows natively. It yields the rows collected by
+/// [`Connection::query`].
+pub type CollectedRows<R, E> =
+    IterStream<std::iter::Map<std::vec::IntoIter<R>, fn(R) -> Result<R, E>>>;
+
+/// Streams the rows collected by [`Connection::query`] for [`Connection::query_stream`].
+pub fn collected_rows<R, E>(rows: Vec<R>) -> CollectedRows<R, E> {
+    IterStream::new(rows.into_iter().map(Ok))
 }
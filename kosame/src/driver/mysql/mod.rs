@@ -0,0 +1,129 @@
+use mysql::prelude::Queryable;
+
+use crate::driver::{
+    CollectedRows, Connection, Transaction, Transactional, collected_rows, mysql_types::Value,
+};
+
+impl Connection for mysql::Conn {
+    type Dialect = kosame_sql::mysql::Dialect;
+    type Params<'a> = Vec<Value>;
+    type Row = mysql::Row;
+    type Error = mysql::Error;
+    type RowStream<'a>
+        = CollectedRows<mysql::Row, mysql::Error>
+    where
+        Self: 'a;
+
+    async fn exec(&mut self, sql: &str, params: &Self::Params<'_>) -> Result<u64, Self::Error> {
+        self.exec_drop(sql, params.clone())?;
+        Ok(self.affected_rows())
+    }
+
+    async fn query(
+        &mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> Result<Vec<Self::Row>, Self::Error> {
+        Queryable::exec(self, sql, params.clone())
+    }
+
+    async fn query_stream<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> Result<Self::RowStream<'a>, Self::Error> {
+        Queryable::exec(self, sql, params.clone()).map(collected_rows)
+    }
+}
+
+impl Connection for mysql::PooledConn {
+    type Dialect = kosame_sql::mysql::Dialect;
+    type Params<'a> = Vec<Value>;
+    type Row = mysql::Row;
+    type Error = mysql::Error;
+    type RowStream<'a>
+        = CollectedRows<mysql::Row, mysql::Error>
+    where
+        Self: 'a;
+
+    async fn exec(&mut self, sql: &str, params: &Self::Params<'_>) -> Result<u64, Self::Error> {
+        self.exec_drop(sql, params.clone())?;
+        Ok(self.affected_rows())
+    }
+
+    async fn query(
+        &mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> Result<Vec<Self::Row>, Self::Error> {
+        Queryable::exec(self, sql, params.clone())
+    }
+
+    async fn query_stream<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> Result<Self::RowStream<'a>, Self::Error> {
+        Queryable::exec(self, sql, params.clone()).map(collected_rows)
+    }
+}
+
+impl Connection for mysql::Transaction<'_> {
+    type Dialect = kosame_sql::mysql::Dialect;
+    type Params<'a> = Vec<Value>;
+    type Row = mysql::Row;
+    type Error = mysql::Error;
+    type RowStream<'a>
+        = CollectedRows<mysql::Row, mysql::Error>
+    where
+        Self: 'a;
+
+    async fn exec(&mut self, sql: &str, params: &Self::Params<'_>) -> Result<u64, Self::Error> {
+        self.exec_drop(sql, params.clone())?;
+        Ok(self.affected_rows())
+    }
+
+    async fn query(
+        &mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> Result<Vec<Self::Row>, Self::Error> {
+        Queryable::exec(self, sql, params.clone())
+    }
+
+    async fn query_stream<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> Result<Self::RowStream<'a>, Self::Error> {
+        Queryable::exec(self, sql, params.clone()).map(collected_rows)
+    }
+}
+
+// MySQL supports savepoints, but the `mysql` crate does not expose them, so its transactions
+// cannot be nested.
+impl Transactional for mysql::Conn {
+    type Transaction<'a> = mysql::Transaction<'a>;
+
+    async fn begin(&mut self) -> Result<Self::Transaction<'_>, Self::Error> {
+        self.start_transaction(mysql::TxOpts::default())
+    }
+}
+
+impl Transactional for mysql::PooledConn {
+    type Transaction<'a> = mysql::Transaction<'a>;
+
+    async fn begin(&mut self) -> Result<Self::Transaction<'_>, Self::Error> {
+        self.start_transaction(mysql::TxOpts::default())
+    }
+}
+
+impl Transaction for mysql::Transaction<'_> {
+    async fn commit(self) -> Result<(), Self::Error> {
+        mysql::Transaction::<'_>::commit(self)
+    }
+
+    async fn rollback(self) -> Result<(), Self::Error> {
+        mysql::Transaction::<'_>::rollback(self)
+    }
+}
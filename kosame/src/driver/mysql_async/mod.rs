@@ -0,0 +1,93 @@
+use mysql_async::prelude::Queryable;
+
+use crate::driver::{
+    CollectedRows, Connection, Transaction, Transactional, collected_rows, mysql_types::Value,
+};
+
+impl Connection for mysql_async::Conn {
+    type Dialect = kosame_sql::mysql::Dialect;
+    type Params<'a> = Vec<Value>;
+    type Row = mysql_async::Row;
+    type Error = mysql_async::Error;
+    type RowStream<'a>
+        = CollectedRows<mysql_async::Row, mysql_async::Error>
+    where
+        Self: 'a;
+
+    async fn exec(&mut self, sql: &str, params: &Self::Params<'_>) -> Result<u64, Self::Error> {
+        self.exec_drop(sql, params.clone()).await?;
+        Ok(self.affected_rows())
+    }
+
+    async fn query(
+        &mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> Result<Vec<Self::Row>, Self::Error> {
+        Queryable::exec(self, sql, params.clone()).await
+    }
+
+    async fn query_stream<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> Result<Self::RowStream<'a>, Self::Error> {
+        Queryable::exec(self, sql, params.clone())
+            .await
+            .map(collected_rows)
+    }
+}
+
+impl Connection for mysql_async::Transaction<'_> {
+    type Dialect = kosame_sql::mysql::Dialect;
+    type Params<'a> = Vec<Value>;
+    type Row = mysql_async::Row;
+    type Error = mysql_async::Error;
+    type RowStream<'a>
+        = CollectedRows<mysql_async::Row, mysql_async::Error>
+    where
+        Self: 'a;
+
+    async fn exec(&mut self, sql: &str, params: &Self::Params<'_>) -> Result<u64, Self::Error> {
+        self.exec_drop(sql, params.clone()).await?;
+        Ok(self.affected_rows())
+    }
+
+    async fn query(
+        &mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> Result<Vec<Self::Row>, Self::Error> {
+        Queryable::exec(self, sql, params.clone()).await
+    }
+
+    async fn query_stream<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> Result<Self::RowStream<'a>, Self::Error> {
+        Queryable::exec(self, sql, params.clone())
+            .await
+            .map(collected_rows)
+    }
+}
+
+// MySQL supports savepoints, but the `mysql_async` crate does not expose them, so its
+// transactions cannot be nested.
+impl Transactional for mysql_async::Conn {
+    type Transaction<'a> = mysql_async::Transaction<'a>;
+
+    async fn begin(&mut self) -> Result<Self::Transaction<'_>, Self::Error> {
+        self.start_transaction(mysql_async::TxOpts::default()).await
+    }
+}
+
+impl Transaction for mysql_async::Transaction<'_> {
+    async fn commit(self) -> Result<(), Self::Error> {
+        mysql_async::Transaction::<'_>::commit(self).await
+    }
+
+    async fn rollback(self) -> Result<(), Self::Error> {
+        mysql_async::Transaction::<'_>::rollback(self).await
+    }
+}
@@ -0,0 +1,102 @@
+pub use mysql_common::{Row, Value, value::convert::FromValue};
+
+/// A bind parameter value accepted by the MySQL drivers.
+///
+/// This mirrors `mysql_common`'s `ToValue` with an additional `Debug` bound, so that generated
+/// `Params` structs can derive `Debug` like they do for the other drivers.
+pub trait ToValue: std::fmt::Debug {
+    fn to_value(&self) -> Value;
+}
+
+impl<T> ToValue for T
+where
+    T: mysql_common::value::convert::ToValue + std::fmt::Debug,
+{
+    fn to_value(&self) -> Value {
+        mysql_common::value::convert::ToValue::to_value(self)
+    }
+}
+
+/// A bind parameter value holding an array, e.g. in `= any(:ids)`.
+///
+/// MySQL has no array type, so each element is bound as a separate parameter.
+pub trait ToValueArray: std::fmt::Debug {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn to_values(&self) -> Vec<Value>;
+}
+
+impl<T> ToValueArray for [T]
+where
+    T: ToValue,
+{
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn to_values(&self) -> Vec<Value> {
+        self.iter().map(ToValue::to_value).collect()
+    }
+}
+
+impl<T, const N: usize> ToValueArray for [T; N]
+where
+    T: ToValue,
+{
+    fn len(&self) -> usize {
+        N
+    }
+
+    fn to_values(&self) -> Vec<Value> {
+        self.as_slice().to_values()
+    }
+}
+
+impl<T> ToValueArray for Vec<T>
+where
+    T: ToValue,
+{
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn to_values(&self) -> Vec<Value> {
+        self.as_slice().to_values()
+    }
+}
+
+impl<A> ToValueArray for &A
+where
+    A: ToValueArray + ?Sized,
+{
+    fn len(&self) -> usize {
+        A::len(self)
+    }
+
+    fn to_values(&self) -> Vec<Value> {
+        A::to_values(self)
+    }
+}
+
+/// Deserializes the value of the column at `index`.
+///
+/// # Panics
+///
+/// Panics if the index is out of bounds or if the value cannot be converted to `T`.
+pub fn get<T>(row: &Row, index: usize) -> T
+where
+    T: FromValue,
+{
+    match row.get_opt(index) {
+        Some(Ok(value)) => value,
+        Some(Err(error)) => panic!("error retrieving column {index}: {error}"),
+        None => panic!(
+            "column index {index} out of range for row of length {}",
+            row.len()
+        ),
+    }
+}
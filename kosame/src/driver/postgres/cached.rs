@@ -0,0 +1,143 @@
+use std::borrow::BorrowMut;
+
+use fallible_iterator::FallibleIterator;
+use postgres::GenericClient;
+
+use crate::{
+    driver::{Connection, Transaction, Transactional, postgres_types::StatementCache},
+    stream::IterStream,
+};
+
+use super::RowIterStream;
+
+/// A connection wrapper that prepares every statement only once and reuses the prepared
+/// statement on subsequent calls.
+///
+/// Transactions begun on a `CachedClient` share its [`StatementCache`], since prepared statements
+/// outlive the transaction they were prepared in.
+pub struct CachedClient<C, S = StatementCache> {
+    client: C,
+    statements: S,
+}
+
+impl<C> CachedClient<C> {
+    pub fn new(client: C) -> Self {
+        Self {
+            client,
+            statements: StatementCache::new(),
+        }
+    }
+}
+
+impl<C, S> CachedClient<C, S> {
+    pub fn get_ref(&self) -> &C {
+        &self.client
+    }
+
+    pub fn get_mut(&mut self) -> &mut C {
+        &mut self.client
+    }
+
+    pub fn into_inner(self) -> C {
+        self.client
+    }
+}
+
+impl<C, S> CachedClient<C, S>
+where
+    S: BorrowMut<StatementCache>,
+{
+    pub fn statements(&self) -> &StatementCache {
+        self.statements.borrow()
+    }
+
+    /// Forgets all prepared statements, e.g. after the database schema was changed.
+    pub fn clear(&mut self) {
+        self.statements.borrow_mut().clear();
+    }
+}
+
+impl<C, S> CachedClient<C, S>
+where
+    C: GenericClient,
+    S: BorrowMut<StatementCache>,
+{
+    async fn prepare(&mut self, sql: &str) -> Result<postgres::Statement, postgres::Error> {
+        let client = &mut self.client;
+        self.statements
+            .borrow_mut()
+            .get_or_prepare(sql, || std::future::ready(client.prepare(sql)))
+            .await
+    }
+}
+
+impl<C, S> Connection for CachedClient<C, S>
+where
+    C: GenericClient + Send,
+    S: BorrowMut<StatementCache> + Send,
+{
+    type Dialect = kosame_sql::postgres::Dialect;
+    type Params<'a> = Vec<&'a (dyn postgres_types::ToSql + std::marker::Sync + 'a)>;
+    type Row = postgres::Row;
+    type Error = postgres::Error;
+    type RowStream<'a>
+        = RowIterStream<'a>
+    where
+        Self: 'a;
+
+    async fn exec(&mut self, sql: &str, params: &Self::Params<'_>) -> Result<u64, Self::Error> {
+        let statement = self.prepare(sql).await?;
+        self.client.execute(&statement, params)
+    }
+
+    async fn query(
+        &mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> Result<Vec<Self::Row>, Self::Error> {
+        let statement = self.prepare(sql).await?;
+        self.client.query(&statement, params)
+    }
+
+    async fn query_stream<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> Result<Self::RowStream<'a>, Self::Error> {
+        let statement = self.prepare(sql).await?;
+        self.client
+            .query_raw(&statement, params.iter().copied())
+            .map(|rows| IterStream::new(rows.iterator()))
+    }
+}
+
+impl<C, S> Transactional for CachedClient<C, S>
+where
+    C: GenericClient + Send,
+    S: BorrowMut<StatementCache> + Send,
+{
+    type Transaction<'a>
+        = CachedClient<postgres::Transaction<'a>, &'a mut StatementCache>
+    where
+        Self: 'a;
+
+    async fn begin(&mut self) -> Result<Self::Transaction<'_>, Self::Error> {
+        Ok(CachedClient {
+            client: self.client.transaction()?,
+            statements: self.statements.borrow_mut(),
+        })
+    }
+}
+
+impl<S> Transaction for CachedClient<postgres::Transaction<'_>, S>
+where
+    S: BorrowMut<StatementCache> + Send,
+{
+    async fn commit(self) -> Result<(), Self::Error> {
+        self.client.commit()
+    }
+
+    async fn rollback(self) -> Result<(), Self::Error> {
+        self.client.rollback()
+    }
+}
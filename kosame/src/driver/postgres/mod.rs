@@ -1,10 +1,27 @@
-use crate::driver::Connection;
+mod cached;
+
+pub use crate::driver::postgres_types::StatementCache;
+pub use cached::*;
+
+use fallible_iterator::FallibleIterator;
+
+use crate::{
+    driver::{Connection, Transaction, Transactional},
+    stream::IterStream,
+};
+
+/// The row stream of the synchronous client. Every poll blocks until the next row is received.
+pub type RowIterStream<'a> = IterStream<fallible_iterator::Iterator<postgres::RowIter<'a>>>;
 
 impl Connection for postgres::Client {
     type Dialect = kosame_sql::postgres::Dialect;
     type Params<'a> = Vec<&'a (dyn postgres_types::ToSql + std::marker::Sync + 'a)>;
     type Row = postgres::Row;
     type Error = postgres::Error;
+    type RowStream<'a>
+        = RowIterStream<'a>
+    where
+        Self: 'a;
 
     async fn exec(&mut self, sql: &str, params: &Self::Params<'_>) -> Result<u64, Self::Error> {
         postgres::Client::execute(self, sql, params)
@@ -17,6 +34,15 @@ impl Connection for postgres::Client {
     ) -> Result<Vec<Self::Row>, Self::Error> {
         postgres::Client::query(self, sql, params)
     }
+
+    async fn query_stream<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> Result<Self::RowStream<'a>, Self::Error> {
+        postgres::Client::query_raw(self, sql, params.iter().copied())
+            .map(|rows| IterStream::new(rows.iterator()))
+    }
 }
 
 impl Connection for postgres::Transaction<'_> {
@@ -24,6 +50,10 @@ impl Connection for postgres::Transaction<'_> {
     type Params<'a> = Vec<&'a (dyn postgres_types::ToSql + std::marker::Sync + 'a)>;
     type Row = postgres::Row;
     type Error = postgres::Error;
+    type RowStream<'a>
+        = RowIterStream<'a>
+    where
+        Self: 'a;
 
     async fn exec(&mut self, sql: &str, params: &Self::Params<'_>) -> Result<u64, Self::Error> {
         postgres::Transaction::execute(self, sql, params)
@@ -36,4 +66,42 @@ impl Connection for postgres::Transaction<'_> {
     ) -> Result<Vec<Self::Row>, Self::Error> {
         postgres::Transaction::<'_>::query(self, sql, params)
     }
+
+    async fn query_stream<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> Result<Self::RowStream<'a>, Self::Error> {
+        postgres::Transaction::<'_>::query_raw(self, sql, params.iter().copied())
+            .map(|rows| IterStream::new(rows.iterator()))
+    }
+}
+
+impl Transactional for postgres::Client {
+    type Transaction<'a> = postgres::Transaction<'a>;
+
+    async fn begin(&mut self) -> Result<Self::Transaction<'_>, Self::Error> {
+        postgres::Client::transaction(self)
+    }
+}
+
+impl Transactional for postgres::Transaction<'_> {
+    type Transaction<'a>
+        = postgres::Transaction<'a>
+    where
+        Self: 'a;
+
+    async fn begin(&mut self) -> Result<Self::Transaction<'_>, Self::Error> {
+        postgres::Transaction::<'_>::transaction(self)
+    }
+}
+
+impl Transaction for postgres::Transaction<'_> {
+    async fn commit(self) -> Result<(), Self::Error> {
+        postgres::Transaction::<'_>::commit(self)
+    }
+
+    async fn rollback(self) -> Result<(), Self::Error> {
+        postgres::Transaction::<'_>::rollback(self)
+    }
 }
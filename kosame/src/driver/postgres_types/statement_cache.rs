@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+/// The prepared statements of a connection, keyed by their SQL.
+///
+/// The cache is not bounded. kosame only prepares the SQL rendered by its macros, which is a
+/// finite set known at compile time, so the cache holds at most one statement per `statement!`
+/// and `query!` in the program. Connections that run SQL built at runtime should call
+/// [`StatementCache::clear`] from time to time.
+pub struct StatementCache<T = tokio_postgres::Statement> {
+    statements: HashMap<String, T>,
+}
+
+impl<T> StatementCache<T> {
+    pub fn new() -> Self {
+        Self {
+            statements: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.statements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.statements.is_empty()
+    }
+
+    /// Forgets all prepared statements, e.g. after the database schema was changed.
+    pub fn clear(&mut self) {
+        self.statements.clear();
+    }
+}
+
+impl<T> StatementCache<T>
+where
+    T: Clone,
+{
+    /// Returns the statement prepared for `sql`, calling `prepare` only if there is none yet.
+    pub async fn get_or_prepare<E, F>(
+        &mut self,
+        sql: &str,
+        prepare: impl FnOnce() -> F,
+    ) -> Result<T, E>
+    where
+        F: Future<Output = Result<T, E>>,
+    {
+        if let Some(statement) = self.statements.get(sql) {
+            return Ok(statement.clone());
+        }
+        let statement = prepare().await?;
+        self.statements.insert(sql.to_owned(), statement.clone());
+        Ok(statement)
+    }
+}
+
+impl<T> Default for StatementCache<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,341 @@
+use std::borrow::Cow;
+
+use crate::driver::{CollectedRows, Connection, Transactional, collected_rows};
+
+pub use rusqlite::types::{FromSql, Value};
+
+/// A bind parameter value accepted by the rusqlite driver.
+///
+/// This is `rusqlite::ToSql` with an additional `Debug` bound, so that generated `Params` structs
+/// can derive `Debug` like they do for the other drivers.
+pub trait ToSql: rusqlite::ToSql + std::fmt::Debug {}
+
+impl<T> ToSql for T where T: rusqlite::ToSql + std::fmt::Debug + ?Sized {}
+
+/// A bind parameter value holding an array, e.g. in `= any(:ids)`.
+///
+/// SQLite has no array type, so each element is bound as a separate parameter.
+pub trait ToSqlArray: std::fmt::Debug {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn elements(&self) -> Vec<&dyn ToSql>;
+}
+
+impl<T> ToSqlArray for [T]
+where
+    T: ToSql,
+{
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn elements(&self) -> Vec<&dyn ToSql> {
+        self.iter().map(|element| element as &dyn ToSql).collect()
+    }
+}
+
+impl<T, const N: usize> ToSqlArray for [T; N]
+where
+    T: ToSql,
+{
+    fn len(&self) -> usize {
+        N
+    }
+
+    fn elements(&self) -> Vec<&dyn ToSql> {
+        self.as_slice().elements()
+    }
+}
+
+impl<T> ToSqlArray for Vec<T>
+where
+    T: ToSql,
+{
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn elements(&self) -> Vec<&dyn ToSql> {
+        self.as_slice().elements()
+    }
+}
+
+impl<A> ToSqlArray for &A
+where
+    A: ToSqlArray + ?Sized,
+{
+    fn len(&self) -> usize {
+        A::len(self)
+    }
+
+    fn elements(&self) -> Vec<&dyn ToSql> {
+        A::elements(self)
+    }
+}
+
+/// An owned result row.
+///
+/// `rusqlite::Row` borrows the prepared statement it was produced by, so the column values are
+/// copied out before the rows are handed to the generated row types.
+#[derive(Debug, Clone)]
+pub struct Row {
+    values: Vec<Value>,
+}
+
+impl Row {
+    fn from_rusqlite(row: &rusqlite::Row<'_>) -> rusqlite::Result<Self> {
+        let column_count = row.as_ref().column_count();
+        let values = (0..column_count)
+            .map(|index| row.get(index))
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(Self { values })
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Deserializes the value of the column at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds or if the value cannot be converted to `T`.
+    pub fn get<T>(&self, index: usize) -> T
+    where
+        T: FromSql,
+    {
+        let Some(value) = self.values.get(index) else {
+            panic!(
+                "column index {index} out of range for row of length {}",
+                self.len()
+            );
+        };
+        match T::column_result(value.into()) {
+            Ok(value) => value,
+            Err(error) => panic!("error retrieving column {index}: {error}"),
+        }
+    }
+}
+
+fn exec(
+    connection: &rusqlite::Connection,
+    sql: &str,
+    params: &[(Cow<'_, str>, &dyn ToSql)],
+) -> rusqlite::Result<u64> {
+    let params = driver_params(params);
+    connection
+        .execute(sql, params.as_slice())
+        .map(|count| count as u64)
+}
+
+fn query(
+    connection: &rusqlite::Connection,
+    sql: &str,
+    params: &[(Cow<'_, str>, &dyn ToSql)],
+) -> rusqlite::Result<Vec<Row>> {
+    let params = driver_params(params);
+    let mut statement = connection.prepare(sql)?;
+    let rows = statement.query_map(params.as_slice(), Row::from_rusqlite)?;
+    rows.collect()
+}
+
+fn driver_params<'a>(
+    params: &'a [(Cow<'_, str>, &'a dyn ToSql)],
+) -> Vec<(&'a str, &'a dyn rusqlite::ToSql)> {
+    params
+        .iter()
+        .map(|(name, value)| (name.as_ref(), *value as &dyn rusqlite::ToSql))
+        .collect()
+}
+
+// rusqlite is synchronous and its connections are not `Sync`, so the statements are executed
+// eagerly and the result is wrapped in a ready future.
+impl Connection for rusqlite::Connection {
+    type Dialect = kosame_sql::sqlite::Dialect;
+    type Params<'a> = Vec<(Cow<'a, str>, &'a (dyn ToSql + 'a))>;
+    type Row = Row;
+    type Error = rusqlite::Error;
+    type RowStream<'a>
+        = CollectedRows<Row, rusqlite::Error>
+    where
+        Self: 'a;
+
+    fn exec(
+        &mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> impl Future<Output = Result<u64, Self::Error>> + Send {
+        std::future::ready(exec(self, sql, params))
+    }
+
+    fn query(
+        &mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> impl Future<Output = Result<Vec<Self::Row>, Self::Error>> + Send {
+        std::future::ready(query(self, sql, params))
+    }
+
+    fn query_stream<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> impl Future<Output = Result<Self::RowStream<'a>, Self::Error>> + Send {
+        std::future::ready(query(self, sql, params).map(collected_rows))
+    }
+}
+
+impl Connection for rusqlite::Transaction<'_> {
+    type Dialect = kosame_sql::sqlite::Dialect;
+    type Params<'a> = Vec<(Cow<'a, str>, &'a (dyn ToSql + 'a))>;
+    type Row = Row;
+    type Error = rusqlite::Error;
+    type RowStream<'a>
+        = CollectedRows<Row, rusqlite::Error>
+    where
+        Self: 'a;
+
+    fn exec(
+        &mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> impl Future<Output = Result<u64, Self::Error>> + Send {
+        std::future::ready(exec(self, sql, params))
+    }
+
+    fn query(
+        &mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> impl Future<Output = Result<Vec<Self::Row>, Self::Error>> + Send {
+        std::future::ready(query(self, sql, params))
+    }
+
+    fn query_stream<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> impl Future<Output = Result<Self::RowStream<'a>, Self::Error>> + Send {
+        std::future::ready(query(self, sql, params).map(collected_rows))
+    }
+}
+
+/// A transaction opened by [`Transactional::begin`] on a `rusqlite::Connection`.
+///
+/// Unlike `rusqlite::Transaction`, this borrows the connection mutably, which keeps it `Send`.
+/// Nested transactions are backed by savepoints. Dropping the transaction without committing it
+/// rolls it back.
+pub struct Transaction<'a> {
+    connection: &'a mut rusqlite::Connection,
+    depth: u32,
+    finished: bool,
+}
+
+impl<'a> Transaction<'a> {
+    fn begin(connection: &'a mut rusqlite::Connection, depth: u32) -> rusqlite::Result<Self> {
+        if depth == 0 {
+            connection.execute_batch("begin")?;
+        } else {
+            connection.execute_batch(&format!("savepoint kosame_{depth}"))?;
+        }
+        Ok(Self {
+            connection,
+            depth,
+            finished: false,
+        })
+    }
+
+    /// Ends the transaction. If this fails, e.g. because a deferred constraint is violated on
+    /// commit, the transaction stays open and is rolled back when it is dropped.
+    fn finish(&mut self, commit: bool) -> rusqlite::Result<()> {
+        let sql = match (self.depth, commit) {
+            (0, true) => "commit".to_owned(),
+            (0, false) => "rollback".to_owned(),
+            (depth, true) => format!("release kosame_{depth}"),
+            (depth, false) => format!("rollback to kosame_{depth}; release kosame_{depth}"),
+        };
+        self.connection.execute_batch(&sql)?;
+        self.finished = true;
+        Ok(())
+    }
+}
+
+impl Drop for Transaction<'_> {
+    fn drop(&mut self) {
+        if !self.finished {
+            let _ = self.finish(false);
+        }
+    }
+}
+
+impl Connection for Transaction<'_> {
+    type Dialect = kosame_sql::sqlite::Dialect;
+    type Params<'a> = Vec<(Cow<'a, str>, &'a (dyn ToSql + 'a))>;
+    type Row = Row;
+    type Error = rusqlite::Error;
+    type RowStream<'a>
+        = CollectedRows<Row, rusqlite::Error>
+    where
+        Self: 'a;
+
+    fn exec(
+        &mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> impl Future<Output = Result<u64, Self::Error>> + Send {
+        std::future::ready(exec(self.connection, sql, params))
+    }
+
+    fn query(
+        &mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> impl Future<Output = Result<Vec<Self::Row>, Self::Error>> + Send {
+        std::future::ready(query(self.connection, sql, params))
+    }
+
+    fn query_stream<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> impl Future<Output = Result<Self::RowStream<'a>, Self::Error>> + Send {
+        std::future::ready(query(self.connection, sql, params).map(collected_rows))
+    }
+}
+
+impl Transactional for rusqlite::Connection {
+    type Transaction<'a> = Transaction<'a>;
+
+    fn begin(&mut self) -> impl Future<Output = Result<Self::Transaction<'_>, Self::Error>> + Send {
+        std::future::ready(Transaction::begin(self, 0))
+    }
+}
+
+impl Transactional for Transaction<'_> {
+    type Transaction<'a>
+        = Transaction<'a>
+    where
+        Self: 'a;
+
+    fn begin(&mut self) -> impl Future<Output = Result<Self::Transaction<'_>, Self::Error>> + Send {
+        std::future::ready(Transaction::begin(self.connection, self.depth + 1))
+    }
+}
+
+impl crate::driver::Transaction for Transaction<'_> {
+    fn commit(mut self) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        std::future::ready(self.finish(true))
+    }
+
+    fn rollback(mut self) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        std::future::ready(self.finish(false))
+    }
+}
@@ -1,10 +1,19 @@
-use crate::driver::Connection;
+mod cached;
+
+pub use crate::driver::postgres_types::StatementCache;
+pub use cached::*;
+
+use crate::driver::{Connection, Transaction, Transactional};
 
 impl Connection for tokio_postgres::Client {
     type Dialect = kosame_sql::postgres::Dialect;
     type Params<'a> = Vec<&'a (dyn postgres_types::ToSql + std::marker::Sync + 'a)>;
     type Row = tokio_postgres::Row;
     type Error = tokio_postgres::Error;
+    type RowStream<'a>
+        = tokio_postgres::RowStream
+    where
+        Self: 'a;
 
     async fn exec(&mut self, sql: &str, params: &Self::Params<'_>) -> Result<u64, Self::Error> {
         tokio_postgres::Client::execute(self, sql, params).await
@@ -17,6 +26,14 @@ impl Connection for tokio_postgres::Client {
     ) -> Result<Vec<Self::Row>, Self::Error> {
         tokio_postgres::Client::query(self, sql, params).await
     }
+
+    fn query_stream<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> impl Future<Output = Result<Self::RowStream<'a>, Self::Error>> + Send {
+        tokio_postgres::Client::query_raw(self, sql, params.iter().copied())
+    }
 }
 
 impl Connection for tokio_postgres::Transaction<'_> {
@@ -24,6 +41,10 @@ impl Connection for tokio_postgres::Transaction<'_> {
     type Params<'a> = Vec<&'a (dyn postgres_types::ToSql + std::marker::Sync + 'a)>;
     type Row = tokio_postgres::Row;
     type Error = tokio_postgres::Error;
+    type RowStream<'a>
+        = tokio_postgres::RowStream
+    where
+        Self: 'a;
 
     async fn exec(&mut self, sql: &str, params: &Self::Params<'_>) -> Result<u64, Self::Error> {
         tokio_postgres::Transaction::execute(self, sql, params).await
@@ -36,4 +57,41 @@ impl Connection for tokio_postgres::Transaction<'_> {
     ) -> Result<Vec<Self::Row>, Self::Error> {
         tokio_postgres::Transaction::<'_>::query(self, sql, params).await
     }
+
+    fn query_stream<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> impl Future<Output = Result<Self::RowStream<'a>, Self::Error>> + Send {
+        tokio_postgres::Transaction::<'_>::query_raw(self, sql, params.iter().copied())
+    }
+}
+
+impl Transactional for tokio_postgres::Client {
+    type Transaction<'a> = tokio_postgres::Transaction<'a>;
+
+    async fn begin(&mut self) -> Result<Self::Transaction<'_>, Self::Error> {
+        tokio_postgres::Client::transaction(self).await
+    }
+}
+
+impl Transactional for tokio_postgres::Transaction<'_> {
+    type Transaction<'a>
+        = tokio_postgres::Transaction<'a>
+    where
+        Self: 'a;
+
+    async fn begin(&mut self) -> Result<Self::Transaction<'_>, Self::Error> {
+        tokio_postgres::Transaction::<'_>::transaction(self).await
+    }
+}
+
+impl Transaction for tokio_postgres::Transaction<'_> {
+    async fn commit(self) -> Result<(), Self::Error> {
+        tokio_postgres::Transaction::<'_>::commit(self).await
+    }
+
+    async fn rollback(self) -> Result<(), Self::Error> {
+        tokio_postgres::Transaction::<'_>::rollback(self).await
+    }
 }
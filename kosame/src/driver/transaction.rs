@@ -0,0 +1,52 @@
+use crate::driver::Connection;
+
+/// A connection that can open transactions.
+///
+/// Transactions of drivers that support savepoints implement `Transactional` themselves. Calling
+/// [`Transactional::begin`] on them opens a nested transaction backed by a savepoint.
+pub trait Transactional: Connection {
+    type Transaction<'a>: Transaction<Dialect = Self::Dialect, Row = Self::Row, Error = Self::Error>
+    where
+        Self: 'a;
+
+    fn begin(&mut self) -> impl Future<Output = Result<Self::Transaction<'_>, Self::Error>> + Send;
+
+    /// Runs `f` in a new transaction. The transaction is committed if `f` returns `Ok` and rolled
+    /// back otherwise.
+    ///
+    /// Most driver connection types have an inherent `transaction` method that shadows this one.
+    /// Call it as `Transactional::transaction(&mut connection, ...)` on concrete driver types.
+    fn transaction<'c, T, F>(&'c mut self, f: F) -> impl Future<Output = crate::Result<T>>
+    where
+        F: AsyncFnOnce(&mut Self::Transaction<'c>) -> crate::Result<T>,
+    {
+        async move {
+            let mut transaction = self
+                .begin()
+                .await
+                .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)?;
+            match f(&mut transaction).await {
+                Ok(value) => {
+                    transaction
+                        .commit()
+                        .await
+                        .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)?;
+                    Ok(value)
+                }
+                Err(error) => {
+                    // The error returned by `f` is more useful to the caller than a failed
+                    // rollback, which the database performs anyway once the connection is closed.
+                    let _ = transaction.rollback().await;
+                    Err(error)
+                }
+            }
+        }
+    }
+}
+
+/// An open transaction. Dropping a transaction without committing it rolls it back.
+pub trait Transaction: Connection + Sized {
+    fn commit(self) -> impl Future<Output = Result<(), Self::Error>> + Send;
+
+    fn rollback(self) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
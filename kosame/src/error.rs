@@ -1,7 +1,13 @@
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
-    #[error("unexpected number of rows in result set")]
-    RowCount,
+    #[error("expected {expected}, but the result set contains {actual} rows: {sql}")]
+    RowCount {
+        expected: ExpectedRows,
+        actual: usize,
+        /// The SQL that was executed, with array bind parameters expanded. See
+        /// [`Statement::exec_sql`](crate::statement::Statement::exec_sql).
+        sql: std::borrow::Cow<'static, str>,
+    },
     #[error("SQL formatting failed")]
     FmtSql(
         #[from]
@@ -12,8 +18,76 @@ pub enum Error {
     Driver(
         #[from]
         #[source]
-        Box<dyn std::error::Error>,
+        Box<dyn std::error::Error + Send + Sync>,
     ),
 }
 
+/// The number of rows a query method expects in the result set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpectedRows {
+    One,
+    AtMostOne,
+}
+
+impl std::fmt::Display for ExpectedRows {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::One => f.write_str("exactly one row"),
+            Self::AtMostOne => f.write_str("at most one row"),
+        }
+    }
+}
+
+impl Error {
+    /// The SQLSTATE code reported by the database, if this is a database error of a driver that
+    /// supports SQLSTATE codes. Currently, only the PostgreSQL drivers do.
+    pub fn sql_state(&self) -> Option<&str> {
+        #[cfg(any(feature = "postgres", feature = "tokio-postgres"))]
+        if let Some(error) = self.postgres_error() {
+            return error.code().map(|code| code.code());
+        }
+        None
+    }
+
+    /// Whether a `unique` or `primary key` constraint was violated (SQLSTATE `23505`).
+    pub fn is_unique_violation(&self) -> bool {
+        self.sql_state() == Some("23505")
+    }
+
+    /// Whether a `foreign key` constraint was violated (SQLSTATE `23503`).
+    pub fn is_foreign_key_violation(&self) -> bool {
+        self.sql_state() == Some("23503")
+    }
+
+    /// Whether a `not null` constraint was violated (SQLSTATE `23502`).
+    pub fn is_not_null_violation(&self) -> bool {
+        self.sql_state() == Some("23502")
+    }
+
+    /// Whether a serializable transaction failed due to concurrent updates (SQLSTATE `40001`).
+    /// Such transactions can usually be retried.
+    pub fn is_serialization_failure(&self) -> bool {
+        self.sql_state() == Some("40001")
+    }
+
+    /// Whether the connection to the database was closed or failed (SQLSTATE class `08`).
+    pub fn is_connection_lost(&self) -> bool {
+        #[cfg(any(feature = "postgres", feature = "tokio-postgres"))]
+        if self.postgres_error().is_some_and(|error| error.is_closed()) {
+            return true;
+        }
+        self.sql_state()
+            .is_some_and(|state| state.starts_with("08"))
+    }
+
+    #[cfg(any(feature = "postgres", feature = "tokio-postgres"))]
+    fn postgres_error(&self) -> Option<&tokio_postgres::Error> {
+        // `postgres::Error` is a re-export of `tokio_postgres::Error`.
+        match self {
+            Self::Driver(error) => error.downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
 pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,132 @@
+/// Resolves to `T` if both types are equal. Used by the macros to check that inferred types agree,
+/// e.g. across the branches of a `case` expression or the operands of `+`.
+#[diagnostic::on_unimplemented(
+    message = "inferred types `{Self}` and `{T}` do not match",
+    note = "operands of arithmetic and the branches of a `case` expression must have the same type, or the field needs a type override like `as name: RustType`"
+)]
+pub trait SameType<T> {
+    type Type;
+}
+
+impl<T> SameType<T> for T {
+    type Type = T;
+}
+
+/// Resolves to `Self` if the database keeps the type `Self` when a value of it is combined with a
+/// literal of type `L`, e.g. `bigint_column + 1` is still a `bigint`. Combinations that change the
+/// type, like `int_column * 1.5` or `smallint_column + 1`, are not implemented.
+#[diagnostic::on_unimplemented(
+    message = "cannot infer the type of `{Self}` combined with a literal of type `{L}`",
+    note = "the database may return a different type, so the field needs a type override like `as name: RustType`"
+)]
+pub trait WithLiteral<L> {
+    type Type;
+}
+
+impl WithLiteral<i32> for i32 {
+    type Type = i32;
+}
+
+impl WithLiteral<i32> for i64 {
+    type Type = i64;
+}
+
+impl WithLiteral<i64> for i64 {
+    type Type = i64;
+}
+
+impl WithLiteral<i32> for f64 {
+    type Type = f64;
+}
+
+impl WithLiteral<f64> for f64 {
+    type Type = f64;
+}
+
+impl WithLiteral<String> for String {
+    type Type = String;
+}
+
+impl WithLiteral<bool> for bool {
+    type Type = bool;
+}
+
+/// Type-level marker for expressions that are never `null`.
+pub struct NotNull;
+
+/// Type-level marker for expressions that may be `null`.
+pub struct Nullable;
+
+/// Whether an expression may be `null`. Table columns expose theirs as
+/// `columns::<column>::Nullability`, so that the macros can combine them without knowing the
+/// column's constraints.
+pub trait Nullability {
+    type Apply<T>;
+}
+
+impl Nullability for NotNull {
+    type Apply<T> = T;
+}
+
+impl Nullability for Nullable {
+    type Apply<T> = Option<T>;
+}
+
+/// Nullable if either side is nullable, e.g. for `a + b`.
+pub trait Or<N> {
+    type Output: Nullability;
+}
+
+impl Or<NotNull> for NotNull {
+    type Output = NotNull;
+}
+
+impl Or<Nullable> for NotNull {
+    type Output = Nullable;
+}
+
+impl<N> Or<N> for Nullable {
+    type Output = Nullable;
+}
+
+/// Nullable only if both sides are nullable, e.g. for `coalesce(a, b)`.
+pub trait And<N> {
+    type Output: Nullability;
+}
+
+impl<N> And<N> for NotNull {
+    type Output = NotNull;
+}
+
+impl And<NotNull> for Nullable {
+    type Output = NotNull;
+}
+
+impl And<Nullable> for Nullable {
+    type Output = Nullable;
+}
+
+/// The result type of `sum(...)` over values of type `Self`.
+#[diagnostic::on_unimplemented(
+    message = "cannot infer the type of `sum` over `{Self}`",
+    note = "the field needs a type override like `as name: RustType`"
+)]
+pub trait Sum {
+    type Output;
+}
+
+impl Sum for i16 {
+    type Output = i64;
+}
+
+impl Sum for i32 {
+    type Output = i64;
+}
+
+impl Sum for f32 {
+    type Output = f32;
+}
+
+impl Sum for f64 {
+    type Output = f64;
+}
@@ -16,10 +16,15 @@ macro_rules! keyword_group {
     };
 }
 
+custom_keyword!(all);
 custom_keyword!(and);
+custom_keyword!(any);
 custom_keyword!(asc);
+custom_keyword!(between);
 custom_keyword!(by);
+custom_keyword!(case);
 custom_keyword!(cast);
+custom_keyword!(conflict);
 custom_keyword!(create);
 custom_keyword!(cross);
 custom_keyword!(default);
@@ -27,13 +32,19 @@ custom_keyword!(delete);
 custom_keyword!(desc);
 custom_keyword!(distinct);
 custom_keyword!(driver);
+custom_keyword!(end);
+custom_keyword!(except);
+custom_keyword!(exists);
+custom_keyword!(filter);
 custom_keyword!(first);
 custom_keyword!(from);
 custom_keyword!(full);
 custom_keyword!(group);
 custom_keyword!(having);
+custom_keyword!(ilike);
 custom_keyword!(inner);
 custom_keyword!(insert);
+custom_keyword!(intersect);
 custom_keyword!(into);
 custom_keyword!(is);
 custom_keyword!(join);
@@ -42,29 +53,44 @@ custom_keyword!(kosame);
 custom_keyword!(last);
 custom_keyword!(lateral);
 custom_keyword!(left);
+custom_keyword!(like);
 custom_keyword!(limit);
+custom_keyword!(locked);
 custom_keyword!(natural);
+custom_keyword!(no);
 custom_keyword!(not);
+custom_keyword!(nothing);
+custom_keyword!(nowait);
 custom_keyword!(null);
 custom_keyword!(nulls);
+custom_keyword!(of);
 custom_keyword!(offset);
 custom_keyword!(on);
 custom_keyword!(or);
 custom_keyword!(order);
+custom_keyword!(over);
+custom_keyword!(partition);
 custom_keyword!(__pass);
 custom_keyword!(primary);
+custom_keyword!(recursive);
 custom_keyword!(references);
 custom_keyword!(rename);
 custom_keyword!(returning);
 custom_keyword!(right);
 custom_keyword!(select);
 custom_keyword!(set);
+custom_keyword!(share);
+custom_keyword!(skip);
 custom_keyword!(__table);
 custom_keyword!(table);
+custom_keyword!(then);
 custom_keyword!(ty);
+custom_keyword!(union);
 custom_keyword!(update);
 custom_keyword!(using);
 custom_keyword!(values);
+custom_keyword!(when);
+custom_keyword!(window);
 custom_keyword!(with);
 
 keyword_group!(group_attribute { driver, rename, ty });
@@ -7,10 +7,13 @@ pub mod keyword;
 
 pub mod driver;
 mod error;
+#[doc(hidden)]
+pub mod infer;
 pub mod params;
 pub mod prelude;
 pub mod query;
 pub mod relation;
 pub mod statement;
+pub mod stream;
 
 pub use error::*;
@@ -3,12 +3,16 @@ mod runner;
 pub use kosame_repr::query::*;
 pub use runner::*;
 
-use crate::{Error, driver::Connection, params::Params};
+use std::borrow::Cow;
+
+use crate::{Error, ExpectedRows, driver::Connection, params::Params, stream::RowStream};
+use futures_core::Stream;
 use pollster::FutureExt;
 
 pub trait Query {
     type Params: std::fmt::Debug;
     type Row;
+    type Dialect: kosame_sql::Dialect;
 
     const REPR: Node<'static>;
 
@@ -16,6 +20,17 @@ pub trait Query {
         &Self::REPR
     }
 
+    /// The SQL of [`Query::REPR`] in the dialect of the query's driver. It is rendered on first use
+    /// and cached for the rest of the program.
+    fn sql(&self) -> Result<&'static str, kosame_sql::Error>;
+
+    /// The SQL that is sent to the database when executing the query with its current bind
+    /// parameters. This is [`Query::sql`], unless the query binds arrays in a dialect without array
+    /// support, in which case each array is expanded into one bind parameter per element.
+    fn exec_sql(&self) -> Result<Cow<'static, str>, kosame_sql::Error> {
+        self.sql().map(Cow::Borrowed)
+    }
+
     fn params(&self) -> &Self::Params;
 
     fn query_vec<'c, C>(
@@ -23,23 +38,51 @@ pub trait Query {
         connection: &mut C,
     ) -> impl Future<Output = crate::Result<Vec<Self::Row>>>
     where
-        C: Connection,
+        C: Connection<Dialect = Self::Dialect>,
         Self::Params: Params<C::Params<'c>>,
         for<'b> Self::Row: From<&'b C::Row>,
     {
         async { RecordArrayRunner {}.run(connection, self).await }
     }
 
-    fn query_one<'c, C>(&self, connection: &mut C) -> impl Future<Output = crate::Result<Self::Row>>
+    /// Like [`Query::query_vec`], but yields the top-level rows as they are received from the
+    /// database instead of collecting them into memory first.
+    fn query_stream<'c, 's, C>(
+        &self,
+        connection: &'s mut C,
+    ) -> impl Future<
+        Output = crate::Result<impl Stream<Item = crate::Result<Self::Row>> + use<'s, Self, C>>,
+    >
     where
-        C: Connection,
+        C: Connection<Dialect = Self::Dialect>,
         Self::Params: Params<C::Params<'c>>,
         for<'b> Self::Row: From<&'b C::Row>,
     {
         async {
-            self.query_opt(connection)
+            let stream = connection
+                .query_stream(&self.exec_sql()?, &self.params().to_driver())
                 .await
-                .and_then(|res| res.ok_or(Error::RowCount))
+                .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)?;
+            Ok(RowStream::new(stream))
+        }
+    }
+
+    fn query_one<'c, C>(&self, connection: &mut C) -> impl Future<Output = crate::Result<Self::Row>>
+    where
+        C: Connection<Dialect = Self::Dialect>,
+        Self::Params: Params<C::Params<'c>>,
+        for<'b> Self::Row: From<&'b C::Row>,
+    {
+        async {
+            let mut rows = self.query_vec(connection).await?;
+            if rows.len() != 1 {
+                return Err(Error::RowCount {
+                    expected: ExpectedRows::One,
+                    actual: rows.len(),
+                    sql: self.exec_sql()?,
+                });
+            }
+            Ok(rows.remove(0))
         }
     }
 
@@ -48,25 +91,26 @@ pub trait Query {
         connection: &mut C,
     ) -> impl Future<Output = crate::Result<Option<Self::Row>>>
     where
-        C: Connection,
+        C: Connection<Dialect = Self::Dialect>,
         Self::Params: Params<C::Params<'c>>,
         for<'b> Self::Row: From<&'b C::Row>,
     {
         async {
-            self.query_vec(connection).await.and_then(|res| {
-                let mut iter = res.into_iter();
-                let row = iter.next();
-                if row.is_some() && iter.next().is_some() {
-                    return Err(Error::RowCount);
-                }
-                Ok(row)
-            })
+            let mut rows = self.query_vec(connection).await?;
+            if rows.len() > 1 {
+                return Err(Error::RowCount {
+                    expected: ExpectedRows::AtMostOne,
+                    actual: rows.len(),
+                    sql: self.exec_sql()?,
+                });
+            }
+            Ok(rows.pop())
         }
     }
 
     fn query_vec_sync<'c, C>(&self, connection: &mut C) -> crate::Result<Vec<Self::Row>>
     where
-        C: Connection,
+        C: Connection<Dialect = Self::Dialect>,
         Self::Params: Params<C::Params<'c>>,
         for<'b> Self::Row: From<&'b C::Row>,
     {
@@ -75,7 +119,7 @@ pub trait Query {
 
     fn query_one_sync<'c, C>(&self, connection: &mut C) -> crate::Result<Self::Row>
     where
-        C: Connection,
+        C: Connection<Dialect = Self::Dialect>,
         Self::Params: Params<C::Params<'c>>,
         for<'b> Self::Row: From<&'b C::Row>,
     {
@@ -84,7 +128,7 @@ pub trait Query {
 
     fn query_opt_sync<'c, C>(&self, connection: &mut C) -> crate::Result<Option<Self::Row>>
     where
-        C: Connection,
+        C: Connection<Dialect = Self::Dialect>,
         Self::Params: Params<C::Params<'c>>,
         for<'b> Self::Row: From<&'b C::Row>,
     {
@@ -13,7 +13,7 @@ pub trait Runner {
         query: &Q,
     ) -> impl Future<Output = crate::Result<Vec<Q::Row>>>
     where
-        C: Connection,
+        C: Connection<Dialect = Q::Dialect>,
         Q: Query + ?Sized,
         Q::Params: Params<C::Params<'a>>,
         for<'b> Q::Row: From<&'b C::Row>;
@@ -10,12 +10,16 @@ use super::*;
 pub struct RecordArrayRunner {}
 
 impl RecordArrayRunner {
+    /// Renders the SQL of a query. `array_lengths` holds the number of elements of each array bind
+    /// parameter, see [`kosame_sql::Formatter::with_array_lengths`].
     pub fn query_to_sql<D: kosame_sql::Dialect>(
         &self,
         query: &(impl Query + ?Sized),
+        array_lengths: &[(&str, usize)],
     ) -> Result<String, kosame_sql::Error> {
         let mut sql = String::new();
-        let mut formatter = kosame_sql::Formatter::<D>::new(&mut sql);
+        let mut formatter =
+            kosame_sql::Formatter::<D>::new(&mut sql).with_array_lengths(array_lengths);
         fmt_node_sql(&mut formatter, query.repr(), None)?;
         Ok(sql)
     }
@@ -24,16 +28,15 @@ impl RecordArrayRunner {
 impl Runner for RecordArrayRunner {
     async fn run<'a, C, Q>(&self, connection: &mut C, query: &Q) -> crate::Result<Vec<Q::Row>>
     where
-        C: Connection,
+        C: Connection<Dialect = Q::Dialect>,
         Q: Query + ?Sized,
         Q::Params: Params<C::Params<'a>>,
         for<'b> Q::Row: From<&'b C::Row>,
     {
-        let sql = self.query_to_sql::<C::Dialect>(query)?;
         let rows = connection
-            .query(&sql, &query.params().to_driver())
+            .query(&query.exec_sql()?, &query.params().to_driver())
             .await
-            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)?;
         Ok(rows.iter().map(Q::Row::from).collect())
     }
 }
@@ -45,6 +48,10 @@ fn fmt_node_sql<D: kosame_sql::Dialect>(
 ) -> std::fmt::Result {
     formatter.write_str("select ")?;
 
+    if let Some(distinct) = node.distinct() {
+        distinct.fmt_sql(formatter)?;
+    }
+
     if relation.is_some() {
         formatter.write_str("row(")?;
     }
@@ -134,5 +141,9 @@ fn fmt_node_sql<D: kosame_sql::Dialect>(
         offset.fmt_sql(formatter)?;
     }
 
+    if let Some(lock) = &node.lock() {
+        lock.fmt_sql(formatter)?;
+    }
+
     Ok(())
 }
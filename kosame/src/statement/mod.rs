@@ -1,11 +1,15 @@
+use std::borrow::Cow;
+
+use futures_core::Stream;
 pub use kosame_repr::command::*;
 use pollster::FutureExt;
 
-use crate::{Error, driver::Connection, params::Params};
+use crate::{Error, ExpectedRows, driver::Connection, params::Params, stream::RowStream};
 
 pub trait Statement {
     type Params: std::fmt::Debug;
     type Row;
+    type Dialect: kosame_sql::Dialect;
 
     const REPR: Command<'static>;
 
@@ -13,27 +17,35 @@ pub trait Statement {
         &Self::REPR
     }
 
+    /// The SQL of [`Statement::REPR`] in the dialect of the statement's driver. It is rendered on
+    /// first use and cached for the rest of the program.
+    fn sql(&self) -> Result<&'static str, kosame_sql::Error>;
+
+    /// The SQL that is sent to the database when executing the statement with its current bind
+    /// parameters. This is [`Statement::sql`], unless the statement binds arrays in a dialect without array
+    /// support, in which case each array is expanded into one bind parameter per element.
+    fn exec_sql(&self) -> Result<Cow<'static, str>, kosame_sql::Error> {
+        self.sql().map(Cow::Borrowed)
+    }
+
     fn params(&self) -> &Self::Params;
 
     fn exec<'c, C>(&self, connection: &mut C) -> impl Future<Output = crate::Result<u64>>
     where
-        C: Connection,
+        C: Connection<Dialect = Self::Dialect>,
         Self::Params: Params<C::Params<'c>>,
     {
         async {
-            use kosame_sql::FmtSql;
-            let sql = self.repr().to_sql_string::<C::Dialect>()?;
-
             Ok(connection
-                .exec(&sql, &self.params().to_driver())
+                .exec(&self.exec_sql()?, &self.params().to_driver())
                 .await
-                .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?)
+                .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)?)
         }
     }
 
     fn exec_sync<'c, C>(&self, connection: &mut C) -> crate::Result<u64>
     where
-        C: Connection,
+        C: Connection<Dialect = Self::Dialect>,
         Self::Params: Params<C::Params<'c>>,
     {
         self.exec(connection).block_on()
@@ -44,32 +56,57 @@ pub trait Statement {
         connection: &mut C,
     ) -> impl Future<Output = crate::Result<Vec<Self::Row>>>
     where
-        C: Connection,
+        C: Connection<Dialect = Self::Dialect>,
         Self::Params: Params<C::Params<'c>>,
         for<'b> Self::Row: From<&'b C::Row>,
     {
         async {
-            use kosame_sql::FmtSql;
-            let sql = self.repr().to_sql_string::<C::Dialect>()?;
-
             let rows = connection
-                .query(&sql, &self.params().to_driver())
+                .query(&self.exec_sql()?, &self.params().to_driver())
                 .await
-                .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+                .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)?;
             Ok(rows.iter().map(Self::Row::from).collect())
         }
     }
 
-    fn query_one<'c, C>(&self, connection: &mut C) -> impl Future<Output = crate::Result<Self::Row>>
+    /// Like [`Statement::query_vec`], but yields the rows as they are received from the database
+    /// instead of collecting them into memory first.
+    fn query_stream<'c, 's, C>(
+        &self,
+        connection: &'s mut C,
+    ) -> impl Future<
+        Output = crate::Result<impl Stream<Item = crate::Result<Self::Row>> + use<'s, Self, C>>,
+    >
     where
-        C: Connection,
+        C: Connection<Dialect = Self::Dialect>,
         Self::Params: Params<C::Params<'c>>,
         for<'b> Self::Row: From<&'b C::Row>,
     {
         async {
-            self.query_opt(connection)
+            let stream = connection
+                .query_stream(&self.exec_sql()?, &self.params().to_driver())
                 .await
-                .and_then(|res| res.ok_or(Error::RowCount))
+                .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)?;
+            Ok(RowStream::new(stream))
+        }
+    }
+
+    fn query_one<'c, C>(&self, connection: &mut C) -> impl Future<Output = crate::Result<Self::Row>>
+    where
+        C: Connection<Dialect = Self::Dialect>,
+        Self::Params: Params<C::Params<'c>>,
+        for<'b> Self::Row: From<&'b C::Row>,
+    {
+        async {
+            let mut rows = self.query_vec(connection).await?;
+            if rows.len() != 1 {
+                return Err(Error::RowCount {
+                    expected: ExpectedRows::One,
+                    actual: rows.len(),
+                    sql: self.exec_sql()?,
+                });
+            }
+            Ok(rows.remove(0))
         }
     }
 
@@ -78,25 +115,26 @@ pub trait Statement {
         connection: &mut C,
     ) -> impl Future<Output = crate::Result<Option<Self::Row>>>
     where
-        C: Connection,
+        C: Connection<Dialect = Self::Dialect>,
         Self::Params: Params<C::Params<'c>>,
         for<'b> Self::Row: From<&'b C::Row>,
     {
         async {
-            self.query_vec(connection).await.and_then(|res| {
-                let mut iter = res.into_iter();
-                let row = iter.next();
-                if row.is_some() && iter.next().is_some() {
-                    return Err(Error::RowCount);
-                }
-                Ok(row)
-            })
+            let mut rows = self.query_vec(connection).await?;
+            if rows.len() > 1 {
+                return Err(Error::RowCount {
+                    expected: ExpectedRows::AtMostOne,
+                    actual: rows.len(),
+                    sql: self.exec_sql()?,
+                });
+            }
+            Ok(rows.pop())
         }
     }
 
     fn query_vec_sync<'c, C>(&self, connection: &mut C) -> crate::Result<Vec<Self::Row>>
     where
-        C: Connection,
+        C: Connection<Dialect = Self::Dialect>,
         Self::Params: Params<C::Params<'c>>,
         for<'b> Self::Row: From<&'b C::Row>,
     {
@@ -105,7 +143,7 @@ pub trait Statement {
 
     fn query_one_sync<'c, C>(&self, connection: &mut C) -> crate::Result<Self::Row>
     where
-        C: Connection,
+        C: Connection<Dialect = Self::Dialect>,
         Self::Params: Params<C::Params<'c>>,
         for<'b> Self::Row: From<&'b C::Row>,
     {
@@ -114,7 +152,7 @@ pub trait Statement {
 
     fn query_opt_sync<'c, C>(&self, connection: &mut C) -> crate::Result<Option<Self::Row>>
     where
-        C: Connection,
+        C: Connection<Dialect = Self::Dialect>,
         Self::Params: Params<C::Params<'c>>,
         for<'b> Self::Row: From<&'b C::Row>,
     {
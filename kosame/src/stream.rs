@@ -0,0 +1,79 @@
+use std::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Adapts a blocking iterator of results into a `Stream`. Every poll blocks on the iterator,
+    /// which is what the synchronous drivers do anyway.
+    pub struct IterStream<I> {
+        iter: I,
+    }
+}
+
+impl<I> IterStream<I> {
+    pub fn new(iter: I) -> Self {
+        Self { iter }
+    }
+}
+
+impl<I> Stream for IterStream<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.project().iter.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+pin_project! {
+    /// Converts a stream of driver rows into a stream of generated row types.
+    pub(crate) struct RowStream<S, R> {
+        #[pin]
+        inner: S,
+        _row: PhantomData<fn() -> R>,
+    }
+}
+
+impl<S, R> RowStream<S, R> {
+    pub(crate) fn new(inner: S) -> Self {
+        Self {
+            inner,
+            _row: PhantomData,
+        }
+    }
+}
+
+impl<S, T, E, R> Stream for RowStream<S, R>
+where
+    S: Stream<Item = Result<T, E>>,
+    E: std::error::Error + Send + Sync + 'static,
+    for<'b> R: From<&'b T>,
+{
+    type Item = crate::Result<R>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().inner.poll_next(cx).map(|item| {
+            item.map(|result| {
+                result
+                    .map(|row| R::from(&row))
+                    .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+                    .map_err(Into::into)
+            })
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
@@ -0,0 +1,6 @@
+#![cfg(all(feature = "rusqlite", feature = "mysql"))]
+
+#[test]
+fn compile_fail() {
+    trybuild::TestCases::new().compile_fail("tests/compile_fail/*.rs");
+}
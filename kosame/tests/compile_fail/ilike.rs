@@ -0,0 +1,21 @@
+mod schema {
+    kosame::table! {
+        #![kosame(driver = "rusqlite")]
+
+        create table posts (
+            id int primary key,
+            title text not null,
+        );
+    }
+}
+
+fn main() {
+    let _ = kosame::statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.title ilike "a" as x: bool from schema::posts
+    };
+    let _ = kosame::statement! {
+        #![kosame(driver = "mysql")]
+        select 1 as x: i32 where "a" not ilike "b"
+    };
+}
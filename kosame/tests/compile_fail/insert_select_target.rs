@@ -0,0 +1,17 @@
+mod schema {
+    kosame::table! {
+        #![kosame(driver = "rusqlite")]
+
+        create table posts (
+            id int primary key,
+            title text not null,
+        );
+    }
+}
+
+fn main() {
+    let _ = kosame::statement! {
+        #![kosame(driver = "rusqlite")]
+        insert into schema::posts select 2, "copy" where posts.id = 1
+    };
+}
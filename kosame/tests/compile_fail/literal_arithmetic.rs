@@ -0,0 +1,25 @@
+mod schema {
+    kosame::table! {
+        #![kosame(driver = "rusqlite")]
+
+        create table posts (
+            id int primary key,
+            upvotes smallint not null,
+        );
+    }
+}
+
+fn main() {
+    let _ = kosame::statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.id * 1.5 as scaled from schema::posts
+    };
+    let _ = kosame::statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.upvotes + 1 as upvotes from schema::posts
+    };
+    let _ = kosame::statement! {
+        #![kosame(driver = "rusqlite")]
+        select 1 + 2.5 as sum
+    };
+}
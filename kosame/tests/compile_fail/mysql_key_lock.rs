@@ -0,0 +1,24 @@
+mod schema {
+    kosame::table! {
+        #![kosame(driver = "mysql")]
+
+        create table posts (
+            id int primary key,
+        );
+    }
+}
+
+fn main() {
+    let _ = kosame::statement! {
+        #![kosame(driver = "mysql")]
+        select posts.id from schema::posts for update
+    };
+    let _ = kosame::statement! {
+        #![kosame(driver = "mysql")]
+        select posts.id from schema::posts for no key update
+    };
+    let _ = kosame::statement! {
+        #![kosame(driver = "mysql")]
+        select posts.id from schema::posts for key share skip locked
+    };
+}
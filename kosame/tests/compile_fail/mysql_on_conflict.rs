@@ -0,0 +1,26 @@
+mod schema {
+    kosame::table! {
+        #![kosame(driver = "mysql")]
+
+        create table posts (
+            id int primary key,
+            title text not null,
+        );
+    }
+}
+
+fn main() {
+    let _ = kosame::statement! {
+        #![kosame(driver = "mysql")]
+        insert into schema::posts values (1, "a") on conflict do update set title = excluded.title
+    };
+    let _ = kosame::statement! {
+        #![kosame(driver = "mysql")]
+        insert into schema::posts values (1, "a")
+        on conflict (id) do update set title = excluded.title
+    };
+    let _ = kosame::statement! {
+        #![kosame(driver = "mysql")]
+        insert into schema::posts values (1, "a") on conflict do nothing
+    };
+}
@@ -0,0 +1,21 @@
+mod schema {
+    kosame::table! {
+        #![kosame(driver = "mysql")]
+
+        create table posts (
+            id int primary key,
+            title text not null,
+        );
+    }
+}
+
+fn main() {
+    let _ = kosame::statement! {
+        #![kosame(driver = "mysql")]
+        insert into schema::posts values (1, "a") returning posts.id
+    };
+    let _ = kosame::statement! {
+        #![kosame(driver = "mysql")]
+        delete from schema::posts returning posts.id
+    };
+}
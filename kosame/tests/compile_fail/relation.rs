@@ -0,0 +1,58 @@
+mod rusqlite_schema {
+    kosame::table! {
+        #![kosame(driver = "rusqlite")]
+
+        create table posts (
+            id int primary key,
+        );
+
+        comments: (id) <= super::rusqlite_schema::comments (post_id),
+    }
+
+    kosame::table! {
+        #![kosame(driver = "rusqlite")]
+
+        create table comments (
+            id int primary key,
+            post_id int not null,
+        );
+    }
+}
+
+mod mysql_schema {
+    kosame::table! {
+        #![kosame(driver = "mysql")]
+
+        create table posts (
+            id int primary key,
+        );
+
+        comments: (id) <= super::mysql_schema::comments (post_id),
+    }
+
+    kosame::table! {
+        #![kosame(driver = "mysql")]
+
+        create table comments (
+            id int primary key,
+            post_id int not null,
+        );
+    }
+}
+
+fn main() {
+    let _ = kosame::query! {
+        #![kosame(driver = "rusqlite")]
+        rusqlite_schema::posts {
+            id,
+            comments { id },
+        }
+    };
+    let _ = kosame::query! {
+        #![kosame(driver = "mysql")]
+        mysql_schema::posts {
+            id,
+            comments { id },
+        }
+    };
+}
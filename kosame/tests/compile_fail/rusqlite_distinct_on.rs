@@ -0,0 +1,21 @@
+mod schema {
+    kosame::table! {
+        #![kosame(driver = "rusqlite")]
+
+        create table posts (
+            id int primary key,
+            title text not null,
+        );
+    }
+}
+
+fn main() {
+    let _ = kosame::statement! {
+        #![kosame(driver = "rusqlite")]
+        select distinct posts.title from schema::posts
+    };
+    let _ = kosame::statement! {
+        #![kosame(driver = "rusqlite")]
+        select distinct on (posts.title) posts.id from schema::posts
+    };
+}
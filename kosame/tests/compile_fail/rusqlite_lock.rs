@@ -0,0 +1,16 @@
+mod schema {
+    kosame::table! {
+        #![kosame(driver = "rusqlite")]
+
+        create table posts (
+            id int primary key,
+        );
+    }
+}
+
+fn main() {
+    let _ = kosame::statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.id from schema::posts for update
+    };
+}
@@ -0,0 +1,24 @@
+#[test]
+fn error_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<kosame::Error>();
+}
+
+#[test]
+fn classification_without_sql_state() {
+    let error = kosame::Error::RowCount {
+        expected: kosame::ExpectedRows::One,
+        actual: 0,
+        sql: "select 1".into(),
+    };
+    assert_eq!(error.sql_state(), None);
+    assert!(!error.is_unique_violation());
+    assert!(!error.is_foreign_key_violation());
+    assert!(!error.is_not_null_violation());
+    assert!(!error.is_serialization_failure());
+    assert!(!error.is_connection_lost());
+
+    let error = kosame::Error::Driver(Box::new(std::fmt::Error));
+    assert_eq!(error.sql_state(), None);
+    assert!(!error.is_unique_violation());
+}
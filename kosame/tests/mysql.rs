@@ -0,0 +1,108 @@
+#![cfg(any(feature = "mysql", feature = "mysql-async"))]
+
+use kosame::{
+    driver::mysql_types::Value, params::Params, sql::FmtSql, statement, statement::Statement,
+};
+
+mod schema {
+    kosame::table! {
+        #![kosame(driver = "mysql")]
+
+        create table posts (
+            id int primary key,
+            title text not null,
+            upvotes int not null,
+        );
+    }
+}
+
+#[test]
+fn positional_bind_params() {
+    let min = 5;
+    let title = "test";
+    let statement = statement! {
+        #![kosame(driver = "mysql")]
+        select posts.id from schema::posts
+        where posts.upvotes >= :min and (posts.title = :title or posts.upvotes < :min * 2)
+    };
+    assert_eq!(
+        "select `posts`.`id` from `posts` where `posts`.`upvotes` >= ? and (`posts`.`title` = ? or `posts`.`upvotes` < ? * 2)",
+        statement
+            .repr()
+            .to_sql_string::<kosame::sql::mysql::Dialect>()
+            .unwrap()
+    );
+    let params: Vec<Value> = statement.params().to_driver();
+    assert_eq!(
+        params,
+        [Value::Int(5), Value::Bytes(b"test".to_vec()), Value::Int(5)]
+    );
+}
+
+#[test]
+fn on_duplicate_key_update() {
+    let title = "test";
+    let statement = statement! {
+        #![kosame(driver = "mysql")]
+        insert into schema::posts values (1, :title, 0)
+        on conflict do update set title = excluded.title, upvotes = posts.upvotes + 1
+    };
+    assert_eq!(
+        "insert into `posts` values (1, ?, 0) as `excluded` on duplicate key update `title` = `excluded`.`title`, `upvotes` = `posts`.`upvotes` + 1",
+        statement
+            .repr()
+            .to_sql_string::<kosame::sql::mysql::Dialect>()
+            .unwrap()
+    );
+}
+
+#[test]
+fn array_bind_params() {
+    let min = 5;
+    let ids = vec![1, 2, 3];
+    let statement = statement! {
+        #![kosame(driver = "mysql")]
+        select posts.id from schema::posts
+        where posts.upvotes >= :min and posts.id = any(:ids) and posts.upvotes < :min * 2
+    };
+    assert_eq!(
+        "select `posts`.`id` from `posts` where `posts`.`upvotes` >= ? and `posts`.`id` in (?, ?, ?) and `posts`.`upvotes` < ? * 2",
+        statement.exec_sql().unwrap()
+    );
+    let params: Vec<Value> = statement.params().to_driver();
+    assert_eq!(
+        params,
+        [
+            Value::Int(5),
+            Value::Int(1),
+            Value::Int(2),
+            Value::Int(3),
+            Value::Int(5)
+        ]
+    );
+
+    let ids: &[i32] = &[];
+    let statement = statement! {
+        #![kosame(driver = "mysql")]
+        select posts.id from schema::posts where posts.id = any(:ids)
+    };
+    assert_eq!(
+        "select `posts`.`id` from `posts` where (`posts`.`id` is null and 1 = 0)",
+        statement.exec_sql().unwrap()
+    );
+}
+
+#[test]
+fn concat() {
+    let statement = statement! {
+        #![kosame(driver = "mysql")]
+        select posts.title || "!" || "?" as title: String from schema::posts
+    };
+    assert_eq!(
+        "select concat(concat(`posts`.`title`, '!'), '?') as `title` from `posts`",
+        statement
+            .repr()
+            .to_sql_string::<kosame::sql::mysql::Dialect>()
+            .unwrap()
+    );
+}
@@ -0,0 +1,121 @@
+#![cfg(any(
+    all(feature = "bb8", feature = "rusqlite"),
+    feature = "deadpool-postgres"
+))]
+
+#[cfg(all(feature = "bb8", feature = "rusqlite"))]
+mod bb8 {
+    use kosame::{statement, statement::Statement};
+
+    mod schema {
+        kosame::table! {
+            #![kosame(driver = "rusqlite")]
+
+            create table posts (
+                id int primary key,
+                title text not null,
+            );
+        }
+    }
+
+    struct Manager;
+
+    impl ::bb8::ManageConnection for Manager {
+        type Connection = rusqlite::Connection;
+        type Error = rusqlite::Error;
+
+        async fn connect(&self) -> Result<Self::Connection, Self::Error> {
+            rusqlite::Connection::open_in_memory()
+        }
+
+        async fn is_valid(&self, _connection: &mut Self::Connection) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn has_broken(&self, _connection: &mut Self::Connection) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn pooled_connection() {
+        // bb8 times out checkouts and opens connections in the background with tokio.
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            let pool = ::bb8::Pool::builder()
+                .max_size(1)
+                .build(Manager)
+                .await
+                .unwrap();
+            let mut connection = pool.get().await.unwrap();
+            connection
+                .execute(
+                    "create table posts (id integer primary key, title text not null)",
+                    (),
+                )
+                .unwrap();
+
+            let inserted = statement! {
+                #![kosame(driver = "rusqlite")]
+                insert into schema::posts values (1, "first"), (2, "second")
+            }
+            .exec(&mut connection)
+            .await
+            .unwrap();
+            assert_eq!(inserted, 2);
+
+            let rows = statement! {
+                #![kosame(driver = "rusqlite")]
+                select posts.title from schema::posts order by posts.id
+            }
+            .query_vec(&mut connection)
+            .await
+            .unwrap();
+            assert_eq!(
+                rows.into_iter().map(|row| row.title).collect::<Vec<_>>(),
+                ["first", "second"]
+            );
+        });
+    }
+}
+
+// Running statements on a deadpool connection needs a PostgreSQL server, so this module only
+// checks that pooled connections can run statements.
+#[cfg(feature = "deadpool-postgres")]
+#[allow(dead_code)]
+mod deadpool_postgres {
+    use futures_core::Stream;
+    use kosame::{statement, statement::Statement};
+
+    mod schema {
+        kosame::table! {
+            #![kosame(driver = "tokio-postgres")]
+
+            create table posts (
+                id int primary key,
+                title text not null,
+            );
+        }
+    }
+
+    async fn run_statement(
+        connection: &mut ::deadpool_postgres::Object,
+    ) -> kosame::Result<impl Stream> {
+        statement! {
+            #![kosame(driver = "tokio-postgres")]
+            insert into schema::posts values (1, "title")
+        }
+        .exec(connection)
+        .await?;
+
+        statement! {
+            #![kosame(driver = "tokio-postgres")]
+            select posts.title from schema::posts
+        }
+        .query_stream(connection)
+        .await
+    }
+}
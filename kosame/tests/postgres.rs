@@ -0,0 +1,54 @@
+#![cfg(any(feature = "postgres", feature = "tokio-postgres"))]
+
+use kosame::driver::{Transactional, postgres_types::StatementCache};
+
+fn prepare(cache: &mut StatementCache<u32>, sql: &str, prepared: &mut u32) -> Result<u32, ()> {
+    pollster::block_on(cache.get_or_prepare(sql, || {
+        *prepared += 1;
+        std::future::ready(if sql.is_empty() {
+            Err(())
+        } else {
+            Ok(*prepared)
+        })
+    }))
+}
+
+#[test]
+fn statement_cache_prepares_once() {
+    let mut cache = StatementCache::new();
+    let mut prepared = 0;
+    assert_eq!(prepare(&mut cache, "select 1", &mut prepared), Ok(1));
+    assert_eq!(prepare(&mut cache, "select 1", &mut prepared), Ok(1));
+    assert_eq!(prepare(&mut cache, "select 2", &mut prepared), Ok(2));
+    assert_eq!(prepare(&mut cache, "select 1", &mut prepared), Ok(1));
+    assert_eq!(prepared, 2);
+    assert_eq!(cache.len(), 2);
+
+    cache.clear();
+    assert_eq!(prepare(&mut cache, "select 1", &mut prepared), Ok(3));
+}
+
+#[test]
+fn statement_cache_skips_failed_statements() {
+    let mut cache = StatementCache::new();
+    let mut prepared = 0;
+    assert_eq!(prepare(&mut cache, "", &mut prepared), Err(()));
+    assert_eq!(prepare(&mut cache, "", &mut prepared), Err(()));
+    assert_eq!(prepared, 2);
+    assert!(cache.is_empty());
+}
+
+#[allow(dead_code)]
+fn assert_transactional<T: Transactional>() {}
+
+#[cfg(feature = "postgres")]
+#[allow(dead_code)]
+fn postgres_cached_client_is_transactional() {
+    assert_transactional::<kosame::driver::postgres::CachedClient<postgres::Client>>();
+}
+
+#[cfg(feature = "tokio-postgres")]
+#[allow(dead_code)]
+fn tokio_postgres_cached_client_is_transactional() {
+    assert_transactional::<kosame::driver::tokio_postgres::CachedClient<tokio_postgres::Client>>();
+}
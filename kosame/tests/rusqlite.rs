@@ -0,0 +1,849 @@
+#![cfg(feature = "rusqlite")]
+
+use kosame::{statement, statement::Statement};
+
+mod schema {
+    kosame::table! {
+        #![kosame(driver = "rusqlite")]
+
+        create table posts (
+            id int primary key,
+            title text not null,
+            content text,
+        );
+    }
+
+    kosame::table! {
+        #![kosame(driver = "rusqlite")]
+
+        create table categories (
+            id int primary key,
+            parent_id int,
+            name text not null,
+        );
+    }
+}
+
+#[test]
+fn insert_and_select() {
+    let mut connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute(
+            "create table posts (id integer primary key, title text not null, content text)",
+            (),
+        )
+        .unwrap();
+
+    let title = "my post";
+    let inserted = statement! {
+        #![kosame(driver = "rusqlite")]
+        insert into schema::posts values (1, :title, null), (2, "another post", "hello")
+    }
+    .exec_sync(&mut connection)
+    .unwrap();
+    assert_eq!(inserted, 2);
+
+    let id = 1;
+    let row = statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.title, posts.content from schema::posts where posts.id = :id
+    }
+    .query_one_sync(&mut connection)
+    .unwrap();
+    assert_eq!(row.title, "my post");
+    assert_eq!(row.content, None);
+
+    let rows = statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.id from schema::posts order by posts.id desc
+    }
+    .query_vec_sync(&mut connection)
+    .unwrap();
+    assert_eq!(rows.iter().map(|row| row.id).collect::<Vec<_>>(), [2, 1]);
+}
+
+#[test]
+fn query_stream() {
+    use futures_core::Stream;
+    use pollster::FutureExt;
+
+    let mut connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "create table posts (id integer primary key, title text not null, content text);
+            insert into posts (id, title) values (1, 'first'), (2, 'second'), (3, 'third');",
+        )
+        .unwrap();
+
+    let statement = statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.title from schema::posts order by posts.id
+    };
+    let stream = statement.query_stream(&mut connection).block_on().unwrap();
+    let mut stream = std::pin::pin!(stream);
+
+    let mut titles = vec![];
+    while let Some(row) = std::future::poll_fn(|cx| stream.as_mut().poll_next(cx)).block_on() {
+        titles.push(row.unwrap().title);
+    }
+    assert_eq!(titles, ["first", "second", "third"]);
+}
+
+#[test]
+fn row_count() {
+    let mut connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "create table posts (id integer primary key, title text not null, content text);
+            insert into posts (id, title) values (1, 'first'), (2, 'second');",
+        )
+        .unwrap();
+
+    let statement = statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.title from schema::posts
+    };
+    let error = statement.query_opt_sync(&mut connection).unwrap_err();
+    let kosame::Error::RowCount {
+        expected,
+        actual,
+        sql,
+    } = error
+    else {
+        panic!("unexpected error: {error}");
+    };
+    assert_eq!(expected, kosame::ExpectedRows::AtMostOne);
+    assert_eq!(actual, 2);
+    assert_eq!(sql, r#"select "posts"."title" from "posts""#);
+
+    let id = 3;
+    let error = statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.title from schema::posts where posts.id = :id
+    }
+    .query_one_sync(&mut connection)
+    .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        r#"expected exactly one row, but the result set contains 0 rows: select "posts"."title" from "posts" where "posts"."id" = :id"#
+    );
+
+    let ids = vec![1, 2];
+    let error = statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.title from schema::posts where posts.id = any(:ids)
+    }
+    .query_one_sync(&mut connection)
+    .unwrap_err();
+    let kosame::Error::RowCount { sql, .. } = error else {
+        panic!("unexpected error: {error}");
+    };
+    assert_eq!(
+        sql,
+        r#"select "posts"."title" from "posts" where "posts"."id" in (:ids__0, :ids__1)"#
+    );
+}
+
+#[test]
+fn transactions() {
+    use kosame::driver::{Transaction, Transactional};
+    use pollster::FutureExt;
+
+    let mut connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute(
+            "create table posts (id integer primary key, title text not null, content text)",
+            (),
+        )
+        .unwrap();
+
+    // `rusqlite::Connection` has an inherent `transaction` method, so call the trait method
+    // explicitly.
+    let result = Transactional::transaction(&mut connection, async |transaction| {
+        statement! {
+            #![kosame(driver = "rusqlite")]
+            insert into schema::posts values (1, "committed", null)
+        }
+        .exec(transaction)
+        .await?;
+
+        let mut savepoint = transaction.begin().await.unwrap();
+        statement! {
+            #![kosame(driver = "rusqlite")]
+            insert into schema::posts values (2, "rolled back", null)
+        }
+        .exec(&mut savepoint)
+        .await?;
+        savepoint.rollback().await.unwrap();
+
+        Ok(())
+    })
+    .block_on();
+    assert!(result.is_ok());
+
+    let result = Transactional::transaction(&mut connection, async |transaction| {
+        statement! {
+            #![kosame(driver = "rusqlite")]
+            insert into schema::posts values (3, "rolled back", null)
+        }
+        .exec(transaction)
+        .await?;
+        Err::<(), _>(kosame::Error::Driver("rolled back".into()))
+    })
+    .block_on();
+    assert!(result.is_err());
+
+    let rows = statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.title from schema::posts
+    }
+    .query_vec_sync(&mut connection)
+    .unwrap();
+    assert_eq!(
+        rows.into_iter().map(|row| row.title).collect::<Vec<_>>(),
+        ["committed"]
+    );
+}
+
+#[test]
+fn failed_commit() {
+    use kosame::driver::{Connection, Transaction, Transactional};
+    use pollster::FutureExt;
+
+    let mut connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "pragma foreign_keys = on;
+            create table parents (id integer primary key);
+            create table children (
+                parent_id integer references parents (id) deferrable initially deferred
+            );",
+        )
+        .unwrap();
+
+    let mut transaction = connection.begin().block_on().unwrap();
+    transaction
+        .exec("insert into children values (1)", &vec![])
+        .block_on()
+        .unwrap();
+    assert!(transaction.commit().block_on().is_err());
+
+    // The failed commit leaves the transaction open, so dropping it must roll it back.
+    assert!(connection.is_autocommit());
+    let count: i64 = connection
+        .query_row("select count(*) from children", (), |row| row.get(0))
+        .unwrap();
+    assert_eq!(count, 0);
+}
+
+#[cfg(any(feature = "postgres", feature = "tokio-postgres"))]
+#[test]
+fn mixed_drivers() {
+    use kosame::sql::FmtSql;
+
+    let id = 1;
+    let sqlite = statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.title from schema::posts where posts.id = :id
+    };
+    let postgres = kosame::pg_statement! {
+        select posts.title from schema::posts where posts.id = :id
+    };
+    assert_eq!(
+        r#"select "posts"."title" from "posts" where "posts"."id" = :id"#,
+        sqlite
+            .repr()
+            .to_sql_string::<kosame::sql::sqlite::Dialect>()
+            .unwrap()
+    );
+    assert_eq!(
+        r#"select "posts"."title" from "posts" where "posts"."id" = $1"#,
+        postgres
+            .repr()
+            .to_sql_string::<kosame::sql::postgres::Dialect>()
+            .unwrap()
+    );
+}
+
+#[test]
+fn upsert() {
+    let mut connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "create table posts (id integer primary key, title text not null, content text);
+            insert into posts (id, title) values (1, 'first');",
+        )
+        .unwrap();
+
+    let statement = statement! {
+        #![kosame(driver = "rusqlite")]
+        insert into schema::posts values (1, "ignored", null), (2, "second", null)
+        on conflict (id) do nothing
+    };
+    assert_eq!(statement.exec_sync(&mut connection).unwrap(), 1);
+
+    let statement = statement! {
+        #![kosame(driver = "rusqlite")]
+        insert into schema::posts values (1, "updated", "hello"), (2, "kept", null)
+        on conflict (id) do update set title = excluded.title, content = excluded.content
+        where excluded.content is not null
+    };
+    assert_eq!(statement.exec_sync(&mut connection).unwrap(), 1);
+
+    let rows = statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.title, posts.content from schema::posts order by posts.id
+    }
+    .query_vec_sync(&mut connection)
+    .unwrap();
+    assert_eq!(
+        rows.iter()
+            .map(|row| (row.title.as_str(), row.content.as_deref()))
+            .collect::<Vec<_>>(),
+        [("updated", Some("hello")), ("second", None)]
+    );
+}
+
+#[test]
+fn insert_sources() {
+    let mut connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "create table posts (id integer primary key, title text not null default 'untitled', content text);",
+        )
+        .unwrap();
+
+    let title = "first";
+    statement! {
+        #![kosame(driver = "rusqlite")]
+        insert into schema::posts (title) values (:title)
+    }
+    .exec_sync(&mut connection)
+    .unwrap();
+
+    statement! {
+        #![kosame(driver = "rusqlite")]
+        insert into schema::posts default values
+    }
+    .exec_sync(&mut connection)
+    .unwrap();
+
+    let copied = statement! {
+        #![kosame(driver = "rusqlite")]
+        insert into schema::posts (title, content)
+        select posts.title, "copy" as content: String from schema::posts where posts.id = 1
+    }
+    .exec_sync(&mut connection)
+    .unwrap();
+    assert_eq!(copied, 1);
+
+    let rows = statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.id, posts.title, posts.content from schema::posts order by posts.id
+    }
+    .query_vec_sync(&mut connection)
+    .unwrap();
+    assert_eq!(
+        rows.iter()
+            .map(|row| (row.id, row.title.as_str(), row.content.as_deref()))
+            .collect::<Vec<_>>(),
+        [
+            (1, "first", None),
+            (2, "untitled", None),
+            (3, "first", Some("copy"))
+        ]
+    );
+}
+
+#[test]
+fn set_operations() {
+    let mut connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "create table posts (id integer primary key, title text not null, content text);
+            insert into posts (id, title, content) values (1, 'first', 'a'), (2, 'second', null), (3, 'third', 'c');",
+        )
+        .unwrap();
+
+    let rows = statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.title from schema::posts where posts.id = 1
+        union
+        select posts.title from schema::posts where posts.content is not null
+        order by title desc
+    }
+    .query_vec_sync(&mut connection)
+    .unwrap();
+    assert_eq!(
+        rows.iter()
+            .map(|row| row.title.as_str())
+            .collect::<Vec<_>>(),
+        ["third", "first"]
+    );
+}
+
+#[test]
+fn case() {
+    let mut connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "create table posts (id integer primary key, title text not null, content text);
+            insert into posts (id, title, content) values (1, 'first', 'a'), (2, 'second', null);",
+        )
+        .unwrap();
+
+    let rows = statement! {
+        #![kosame(driver = "rusqlite")]
+        select
+            case when posts.content is null then "untitled" else posts.title end as title: String,
+            case when posts.id = 1 then posts.title end as first_title,
+            case posts.id when 1 then posts.content else null end as first_content,
+        from schema::posts
+        order by posts.id
+    }
+    .query_vec_sync(&mut connection)
+    .unwrap();
+
+    let first_titles: Vec<Option<String>> =
+        rows.iter().map(|row| row.first_title.clone()).collect();
+    assert_eq!(first_titles, [Some("first".to_string()), None]);
+    let first_contents: Vec<Option<String>> =
+        rows.iter().map(|row| row.first_content.clone()).collect();
+    assert_eq!(first_contents, [Some("a".to_string()), None]);
+    assert_eq!(rows[1].title, "untitled");
+}
+
+#[test]
+fn filters() {
+    let mut connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "create table posts (id integer primary key, title text not null, content text);
+            insert into posts (id, title, content) values
+                (1, 'first', 'a'), (2, 'second', null), (3, 'third', 'c'), (4, 'fourth', 'd');",
+        )
+        .unwrap();
+
+    let pattern = "f%";
+    let rows = statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.id, posts.title || "!" as title: String
+        from schema::posts
+        where posts.title like :pattern and posts.id not in (4)
+    }
+    .query_vec_sync(&mut connection)
+    .unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].title, "first!");
+
+    let rows = statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.id
+        from schema::posts
+        where posts.id between 2 and 4
+            and posts.id in (select other.id from schema::posts as other where other.content is not null)
+        order by posts.id
+    }
+    .query_vec_sync(&mut connection)
+    .unwrap();
+    let ids: Vec<i32> = rows.iter().map(|row| row.id).collect();
+    assert_eq!(ids, [3, 4]);
+
+    let rows = statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.id
+        from schema::posts
+        where not exists (
+            select 1 as one from schema::posts as newer
+            where newer.id > posts.id
+        )
+    }
+    .query_vec_sync(&mut connection)
+    .unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].id, 4);
+}
+
+#[test]
+fn array_bind_params() {
+    let mut connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "create table posts (id integer primary key, title text not null, content text);
+            insert into posts (id, title, content) values
+                (1, 'first', 'a'), (2, 'second', null), (3, 'third', 'c'), (4, 'fourth', 'd');",
+        )
+        .unwrap();
+
+    let ids = vec![1, 3, 4];
+    let title = "fourth";
+    let statement = statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.id from schema::posts
+        where posts.id = any(:ids) and posts.title <> :title
+        order by posts.id
+    };
+    assert_eq!(
+        r#"select "posts"."id" from "posts" where "posts"."id" in (:ids__0, :ids__1, :ids__2) and "posts"."title" <> :title order by "posts"."id""#,
+        statement.exec_sql().unwrap()
+    );
+    let rows = statement.query_vec_sync(&mut connection).unwrap();
+    let ids: Vec<i32> = rows.iter().map(|row| row.id).collect();
+    assert_eq!(ids, [1, 3]);
+
+    let ids: &[i32] = &[];
+    let rows = statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.id from schema::posts where posts.id = any(:ids)
+    }
+    .query_vec_sync(&mut connection)
+    .unwrap();
+    assert!(rows.is_empty());
+
+    // `= any` of an empty array is false, even for `null`, so its negation holds for every row.
+    let contents: &[&str] = &[];
+    let statement = statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.id from schema::posts where not (posts.content = any(:contents))
+        order by posts.id
+    };
+    assert_eq!(
+        r#"select "posts"."id" from "posts" where not (("posts"."content" is null and 1 = 0)) order by "posts"."id""#,
+        statement.exec_sql().unwrap()
+    );
+    let rows = statement.query_vec_sync(&mut connection).unwrap();
+    let ids: Vec<i32> = rows.iter().map(|row| row.id).collect();
+    assert_eq!(ids, [1, 2, 3, 4]);
+}
+
+#[test]
+fn type_inference() {
+    let mut connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "create table posts (id integer primary key, title text not null, content text);
+            insert into posts (id, title, content) values (1, 'first', 'a'), (2, 'second', null);",
+        )
+        .unwrap();
+
+    let rows = statement! {
+        #![kosame(driver = "rusqlite")]
+        select
+            1 as one,
+            "text" as text,
+            posts.id * 10 + 1 as scaled,
+            posts.id > 1 as later,
+            posts.content = "a" as is_a,
+            posts.content is null as missing,
+            posts.title || "!" as shout,
+            cast(posts.id as bigint) as big_id,
+            coalesce(posts.content, "none") as content,
+        from schema::posts
+        order by posts.id
+    }
+    .query_vec_sync(&mut connection)
+    .unwrap();
+
+    let one: i32 = rows[0].one;
+    let text: &String = &rows[0].text;
+    let scaled: i32 = rows[1].scaled;
+    let later: bool = rows[1].later;
+    let is_a: Option<bool> = rows[1].is_a;
+    let missing: bool = rows[1].missing;
+    let shout: &String = &rows[0].shout;
+    let big_id: i64 = rows[1].big_id;
+    let content: &String = &rows[1].content;
+    assert_eq!(
+        (one, text.as_str(), scaled, later, is_a, missing),
+        (1, "text", 21, true, None, true)
+    );
+    assert_eq!(
+        (shout.as_str(), big_id, content.as_str()),
+        ("first!", 2, "none")
+    );
+
+    let row = statement! {
+        #![kosame(driver = "rusqlite")]
+        select
+            count(posts.id),
+            sum(posts.id) as total,
+            max(posts.id) as last_id,
+            coalesce(sum(posts.id), 0) as total_or_zero,
+        from schema::posts
+        where posts.id > 5
+    }
+    .query_one_sync(&mut connection)
+    .unwrap();
+
+    let count: i64 = row.count;
+    let total: Option<i64> = row.total;
+    let last_id: Option<i32> = row.last_id;
+    let total_or_zero: i64 = row.total_or_zero;
+    assert_eq!((count, total, last_id, total_or_zero), (0, None, None, 0));
+}
+
+#[test]
+fn mixed_arithmetic() {
+    let mut connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "create table posts (id integer primary key, title text not null, content text);
+            insert into posts (id, title, content) values (3, 'first', null);",
+        )
+        .unwrap();
+
+    // Integer columns combined with float literals become floats, so they need type overrides.
+    let row = statement! {
+        #![kosame(driver = "rusqlite")]
+        select
+            posts.id * 1.5 as scaled: f64,
+            posts.id / 2.0 as half: f64,
+            1 + 2.5 as sum: f64,
+            posts.id + 1 as next,
+        from schema::posts
+    }
+    .query_one_sync(&mut connection)
+    .unwrap();
+
+    let next: i32 = row.next;
+    assert_eq!((row.scaled, row.half, row.sum, next), (4.5, 1.5, 3.5, 4));
+}
+#[test]
+fn nullability_inference() {
+    let mut connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "create table posts (id integer primary key, title text not null, content text);
+            insert into posts (id, title, content) values (1, 'first', 'a'), (2, 'second', null);",
+        )
+        .unwrap();
+
+    let rows = statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.title, max(posts.id) as last_id, sum(posts.id) as total
+        from schema::posts
+        group by posts.title
+        order by posts.title
+    }
+    .query_vec_sync(&mut connection)
+    .unwrap();
+
+    let last_id: i32 = rows[0].last_id;
+    let total: i64 = rows[1].total;
+    assert_eq!((last_id, total), (1, 2));
+
+    let rows = statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.content from schema::posts where posts.id > 0 and posts.content is not null
+    }
+    .query_vec_sync(&mut connection)
+    .unwrap();
+
+    let content: &String = &rows[0].content;
+    assert_eq!((rows.len(), content.as_str()), (1, "a"));
+
+    let rows = statement! {
+        #![kosame(driver = "rusqlite")]
+        select posts.id, next.title
+        from schema::posts
+        left join (select posts.id, posts.title from schema::posts) as next on next.id = posts.id + 1
+        order by posts.id
+    }
+    .query_vec_sync(&mut connection)
+    .unwrap();
+
+    let title: &Option<String> = &rows[1].title;
+    assert_eq!((rows[0].title.as_deref(), title), (Some("second"), &None));
+}
+
+#[test]
+fn window_functions() {
+    let mut connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "create table posts (id integer primary key, title text not null, content text);
+            insert into posts (id, title, content) values (1, 'a', 'x'), (2, 'b', null), (3, 'c', 'x');",
+        )
+        .unwrap();
+
+    let rows = statement! {
+        #![kosame(driver = "rusqlite")]
+        select
+            posts.id,
+            row_number() over (partition by posts.content order by posts.id desc) as position,
+            sum(posts.id) over w as running_total,
+            lag(posts.id) over w as previous_id,
+            count(posts.id) filter (where posts.content is null) over () as without_content,
+            max(posts.id) filter (where posts.content is null) as id_without_content,
+        from schema::posts
+        group by posts.id
+        window w as (order by posts.id)
+        order by posts.id
+    }
+    .query_vec_sync(&mut connection)
+    .unwrap();
+
+    let position: i64 = rows[0].position;
+    let running_total: i64 = rows[2].running_total;
+    let previous_id: Option<i32> = rows[0].previous_id;
+    let without_content: i64 = rows[0].without_content;
+    assert_eq!(
+        (position, running_total, previous_id, without_content),
+        (2, 6, None, 1)
+    );
+    let id_without_content: Option<i32> = rows[0].id_without_content;
+    assert_eq!(
+        (
+            rows[1].previous_id,
+            id_without_content,
+            rows[1].id_without_content
+        ),
+        (Some(1), None, Some(2))
+    );
+}
+
+#[test]
+fn recursive_with() {
+    let mut connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "create table categories (id integer primary key, parent_id integer, name text not null);
+            insert into categories (id, parent_id, name)
+            values (1, null, 'root'), (2, 1, 'child'), (3, 2, 'grandchild'), (4, null, 'other');",
+        )
+        .unwrap();
+
+    let rows = statement! {
+        #![kosame(driver = "rusqlite")]
+        with recursive tree as (
+            select categories.id, categories.name, 0 as depth
+            from schema::categories
+            where categories.id = 1
+            union all
+            select categories.id, categories.name, tree.depth + 1
+            from schema::categories
+            inner join tree on categories.parent_id = tree.id
+        )
+        select tree.id, tree.name, tree.depth from tree order by tree.id
+    }
+    .query_vec_sync(&mut connection)
+    .unwrap();
+
+    let depths: Vec<(i32, &str, i32)> = rows
+        .iter()
+        .map(|row| (row.id, row.name.as_str(), row.depth))
+        .collect();
+    assert_eq!(
+        depths,
+        vec![(1, "root", 0), (2, "child", 1), (3, "grandchild", 2)]
+    );
+
+    let rows = statement! {
+        #![kosame(driver = "rusqlite")]
+        with recursive ancestors (id, parent) as (
+            select categories.id, categories.parent_id from schema::categories where categories.id = 3
+            union all
+            select categories.id, categories.parent_id
+            from schema::categories
+            inner join ancestors on categories.id = ancestors.parent
+        )
+        select ancestors.id, ancestors.parent from ancestors order by ancestors.id
+    }
+    .query_vec_sync(&mut connection)
+    .unwrap();
+
+    let parents: Vec<(i32, Option<i32>)> = rows.iter().map(|row| (row.id, row.parent)).collect();
+    assert_eq!(parents, vec![(1, None), (2, Some(1)), (3, Some(2))]);
+}
+
+#[test]
+fn scalar_subqueries() {
+    let mut connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "create table categories (id integer primary key, parent_id integer, name text not null);
+            insert into categories (id, parent_id, name) values
+                (1, null, 'root'), (2, 1, 'first'), (3, 1, 'second'), (4, 2, 'leaf');",
+        )
+        .unwrap();
+
+    let rows = statement! {
+        #![kosame(driver = "rusqlite")]
+        select
+            categories.id,
+            (
+                select count(*) from schema::categories as children
+                where children.parent_id = categories.id
+            ) as child_count,
+            (
+                select parents.name from schema::categories as parents
+                where parents.id = categories.parent_id
+            ) as parent_name,
+        from schema::categories
+        order by categories.id
+    }
+    .query_vec_sync(&mut connection)
+    .unwrap();
+
+    let counts: Vec<(i32, i64, Option<&str>)> = rows
+        .iter()
+        .map(|row| (row.id, row.child_count, row.parent_name.as_deref()))
+        .collect();
+    assert_eq!(
+        counts,
+        vec![
+            (1, 2, None),
+            (2, 1, Some("root")),
+            (3, 0, Some("root")),
+            (4, 0, Some("first"))
+        ]
+    );
+}
+
+#[test]
+fn star_fields() {
+    let mut connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "create table posts (id integer primary key, title text not null, content text);
+            insert into posts (id, title, content) values (1, 'first', 'a'), (2, 'second', null);",
+        )
+        .unwrap();
+
+    let rows = statement! {
+        #![kosame(driver = "rusqlite")]
+        select * from schema::posts order by posts.id
+    }
+    .query_vec_sync(&mut connection)
+    .unwrap();
+    let posts: Vec<(i32, &str, Option<&str>)> = rows
+        .iter()
+        .map(|row| (row.id, row.title.as_str(), row.content.as_deref()))
+        .collect();
+    assert_eq!(posts, vec![(1, "first", Some("a")), (2, "second", None)]);
+
+    let rows = statement! {
+        #![kosame(driver = "rusqlite")]
+        select recent.*, upper(recent.title) as shout: String
+        from (select * from schema::posts where posts.id > 1) as recent
+    }
+    .query_vec_sync(&mut connection)
+    .unwrap();
+    assert_eq!(rows.len(), 1);
+    let content: Option<String> = rows[0].content.clone();
+    assert_eq!(
+        (rows[0].id, rows[0].shout.as_str(), content),
+        (2, "SECOND", None)
+    );
+
+    let row = statement! {
+        #![kosame(driver = "rusqlite")]
+        update schema::posts set content = "b" where posts.id = 2 returning *
+    }
+    .query_one_sync(&mut connection)
+    .unwrap();
+    assert_eq!(
+        (row.id, row.title.as_str(), row.content.as_deref()),
+        (2, "second", Some("b"))
+    );
+}
@@ -1,3 +1,5 @@
+#![cfg(any(feature = "postgres", feature = "tokio-postgres"))]
+
 use kosame::{pg_statement, sql::FmtSql, statement::Statement};
 
 #[test]
@@ -6,7 +8,7 @@ fn basic_select() {
         select 5 as first: i32, true as second: bool
     };
     assert_eq!(
-        "select 5, true",
+        r#"select 5 as "first", true as "second""#,
         statement
             .repr()
             .to_sql_string::<kosame::sql::postgres::Dialect>()
@@ -14,6 +16,16 @@ fn basic_select() {
     );
 }
 
+#[test]
+fn cached_sql() {
+    let statement = pg_statement! {
+        select 5 as first: i32
+    };
+    let sql = statement.sql().unwrap();
+    assert_eq!(r#"select 5 as "first""#, sql);
+    assert!(std::ptr::eq(sql, statement.sql().unwrap()));
+}
+
 mod schema {
     use kosame::pg_table;
 
@@ -48,10 +60,308 @@ fn complex_select() {
         limit :limit
     };
     assert_eq!(
-        r#"select "table_a"."id", "table_b"."title" from "table_a" left join "table_b" on "table_a"."id" = "table_b"."id" where "content" = 'test' group by "title" having "sum"("table_b"."id") > 8 order by "table_a"."id" asc, "table_b"."title" desc nulls last limit $1"#,
+        r#"select "table_a"."id" as "id", "table_b"."title" as "title" from "table_a" left join "table_b" on "table_a"."id" = "table_b"."id" where "content" = 'test' group by "title" having "sum"("table_b"."id") > 8 order by "table_a"."id" asc, "table_b"."title" desc nulls last limit $1"#,
+        statement
+            .repr()
+            .to_sql_string::<kosame::sql::postgres::Dialect>()
+            .unwrap()
+    );
+}
+
+#[test]
+fn insert_sources() {
+    let statement = pg_statement! {
+        insert into schema::table_b (id, title)
+        select table_a.id, table_a.content from schema::table_a
+        on conflict (id) do nothing
+    };
+    assert_eq!(
+        r#"insert into "table_b" ("id", "title") select "table_a"."id", "table_a"."content" from "table_a" on conflict ("id") do nothing"#,
+        statement
+            .repr()
+            .to_sql_string::<kosame::sql::postgres::Dialect>()
+            .unwrap()
+    );
+
+    let statement = pg_statement! {
+        insert into schema::table_b default values returning table_b.id
+    };
+    assert_eq!(
+        r#"insert into "table_b" default values returning "table_b"."id""#,
+        statement
+            .repr()
+            .to_sql_string::<kosame::sql::postgres::Dialect>()
+            .unwrap()
+    );
+}
+
+#[test]
+fn set_operations() {
+    let id = 5;
+    let statement = pg_statement! {
+        select table_a.id, table_a.content as text from schema::table_a where table_a.id > :id
+        union all
+        select table_b.id, table_b.title from schema::table_b
+        except
+        select table_a.id, table_a.content from schema::table_a where table_a.id = :id
+        order by id desc
+        limit 10
+    };
+    assert_eq!(
+        r#"select "table_a"."id", "table_a"."content" as "text" from "table_a" where "table_a"."id" > $1 union all select "table_b"."id", "table_b"."title" from "table_b" except select "table_a"."id", "table_a"."content" from "table_a" where "table_a"."id" = $1 order by "id" desc limit 10"#,
+        statement
+            .repr()
+            .to_sql_string::<kosame::sql::postgres::Dialect>()
+            .unwrap()
+    );
+}
+
+#[test]
+fn distinct() {
+    let statement = pg_statement! {
+        select distinct table_a.content from schema::table_a
+    };
+    assert_eq!(
+        r#"select distinct "table_a"."content" from "table_a""#,
+        statement.sql().unwrap()
+    );
+
+    let statement = pg_statement! {
+        select distinct on (table_a.content) table_a.id, table_a.content
+        from schema::table_a
+        order by table_a.content, table_a.id desc
+    };
+    assert_eq!(
+        r#"select distinct on ("table_a"."content") "table_a"."id", "table_a"."content" from "table_a" order by "table_a"."content", "table_a"."id" desc"#,
+        statement.sql().unwrap()
+    );
+
+    let query = kosame::pg_query! {
+        schema::table_a {
+            distinct on (content)
+            id,
+            content,
+            order by content, id desc
+        }
+    };
+    assert_eq!(
+        r#"select distinct on ("content") "id", "content" from "table_a" order by "content", "id" desc"#,
+        kosame::query::Query::sql(&query).unwrap()
+    );
+}
+
+#[test]
+fn lock() {
+    let statement = pg_statement! {
+        select table_a.id from schema::table_a
+        order by table_a.id
+        limit 1
+        for update skip locked
+    };
+    assert_eq!(
+        r#"select "table_a"."id" from "table_a" order by "table_a"."id" limit 1 for update skip locked"#,
+        statement.sql().unwrap()
+    );
+
+    let statement = pg_statement! {
+        select table_a.id, table_b.title
+        from schema::table_a
+        inner join schema::table_b on table_a.id = table_b.id
+        for no key update of table_a, table_b nowait
+    };
+    assert_eq!(
+        r#"select "table_a"."id", "table_b"."title" from "table_a" inner join "table_b" on "table_a"."id" = "table_b"."id" for no key update of "table_a", "table_b" nowait"#,
+        statement.sql().unwrap()
+    );
+
+    let query = kosame::pg_query! {
+        schema::table_a {
+            id,
+            limit 10
+            for key share
+        }
+    };
+    assert_eq!(
+        r#"select "id" from "table_a" limit 10 for key share"#,
+        kosame::query::Query::sql(&query).unwrap()
+    );
+}
+
+#[test]
+fn case() {
+    let id = 3;
+    let statement = pg_statement! {
+        select
+            case when table_a.id > :id then table_a.content else table_b.title end as text,
+            case table_a.id when 1 then "one" when 2 then "two" end as name: String,
+        from schema::table_a
+        inner join schema::table_b on table_a.id = table_b.id
+    };
+    assert_eq!(
+        r#"select case when "table_a"."id" > $1 then "table_a"."content" else "table_b"."title" end as "text", case "table_a"."id" when 1 then 'one' when 2 then 'two' end as "name" from "table_a" inner join "table_b" on "table_a"."id" = "table_b"."id""#,
+        statement.sql().unwrap()
+    );
+}
+
+#[test]
+fn array_bind_params() {
+    let ids = vec![1, 2, 3];
+    let statement = pg_statement! {
+        select table_a.content from schema::table_a where table_a.id = any(:ids)
+    };
+    assert_eq!(
+        r#"select "table_a"."content" from "table_a" where "table_a"."id" = any($1)"#,
+        statement.sql().unwrap()
+    );
+    assert_eq!(statement.sql().unwrap(), statement.exec_sql().unwrap());
+}
+
+#[test]
+fn operators() {
+    let pattern = "%test%";
+    let low = 1;
+    let statement = pg_statement! {
+        select table_a.content || "!" as content: String
+        from schema::table_a
+        where table_a.content ilike :pattern
+            and table_a.id not between :low and 10
+            and table_a.id in (1, 2, 3)
+            and table_a.id not in (select table_b.id from schema::table_b)
+            and exists (select 1 as one from schema::table_b where table_b.id = table_a.id)
+    };
+    assert_eq!(
+        r#"select "table_a"."content" || '!' as "content" from "table_a" where "table_a"."content" ilike $1 and "table_a"."id" not between $2 and 10 and "table_a"."id" in (1, 2, 3) and "table_a"."id" not in (select "table_b"."id" from "table_b") and exists (select 1 as "one" from "table_b" where "table_b"."id" = "table_a"."id")"#,
+        statement.sql().unwrap()
+    );
+
+    let query = kosame::pg_query! {
+        schema::table_a {
+            id,
+            where content not like "draft%"
+                and exists (select 1 as one from schema::table_b where table_b.id = table_a.id)
+        }
+    };
+    assert_eq!(
+        r#"select "id" from "table_a" where "content" not like 'draft%' and exists (select 1 as "one" from "table_b" where "table_b"."id" = "table_a"."id")"#,
+        kosame::query::Query::sql(&query).unwrap()
+    );
+}
+
+#[test]
+fn window_functions() {
+    let statement = pg_statement! {
+        select
+            table_a.id,
+            row_number() over (partition by table_a.content order by table_a.id desc) as position,
+            sum(table_a.id) over w as partition_total,
+            rank() over (w order by table_a.id) as ranking,
+            count(table_a.id) filter (where table_a.content is null) over () as empty_count,
+        from schema::table_a
+        window w as (partition by table_a.content)
+    };
+    assert_eq!(
+        r#"select "table_a"."id", "row_number"() over (partition by "table_a"."content" order by "table_a"."id" desc) as "position", "sum"("table_a"."id") over "w" as "partition_total", "rank"() over ("w" order by "table_a"."id") as "ranking", "count"("table_a"."id") filter (where "table_a"."content" is null) over () as "empty_count" from "table_a" window "w" as (partition by "table_a"."content")"#,
+        statement
+            .repr()
+            .to_sql_string::<kosame::sql::postgres::Dialect>()
+            .unwrap()
+    );
+}
+
+#[test]
+fn function_and_values_from_items() {
+    fn check_row<S: Statement>(_statement: &S, _check: impl FnOnce(S::Row)) {}
+
+    let ids = vec![1, 2, 3];
+    let statement = pg_statement! {
+        select series.n, letters.id, letters.letter, ids.id as unnested_id: i32
+        from generate_series(1, 10) as series (n)
+            inner join (values (1, "a"), (2, null)) as letters (id, letter) on letters.id = series.n
+            inner join unnest(:ids) as ids (id) on ids.id = letters.id
+    };
+    assert_eq!(
+        r#"select "series"."n", "letters"."id", "letters"."letter", "ids"."id" as "unnested_id" from "generate_series"(1, 10) as "series" ("n") inner join (values (1, 'a'), (2, null)) as "letters" ("id", "letter") on "letters"."id" = "series"."n" inner join "unnest"($1) as "ids" ("id") on "ids"."id" = "letters"."id""#,
+        statement
+            .repr()
+            .to_sql_string::<kosame::sql::postgres::Dialect>()
+            .unwrap()
+    );
+    check_row(&statement, |row| {
+        let _: i32 = row.n;
+        let _: i32 = row.id;
+        let _: Option<String> = row.letter;
+    });
+}
+
+#[test]
+fn scalar_subqueries() {
+    let statement = pg_statement! {
+        select
+            table_a.id,
+            (select count(*) from schema::table_b where table_b.id = table_a.id) as n,
+            (select table_b.title from schema::table_b where table_b.id = table_a.id limit 1),
+        from schema::table_a
+        where (select max(table_b.id) from schema::table_b) > table_a.id
+    };
+    assert_eq!(
+        r#"select "table_a"."id", (select "count"(*) from "table_b" where "table_b"."id" = "table_a"."id") as "n", (select "table_b"."title" from "table_b" where "table_b"."id" = "table_a"."id" limit 1) from "table_a" where (select "max"("table_b"."id") from "table_b") > "table_a"."id""#,
+        statement
+            .repr()
+            .to_sql_string::<kosame::sql::postgres::Dialect>()
+            .unwrap()
+    );
+}
+
+#[test]
+fn star_fields() {
+    fn check_row<S: Statement>(_statement: &S, _check: impl FnOnce(S::Row)) {}
+
+    let statement = pg_statement! {
+        select * from schema::table_b
+    };
+    assert_eq!(
+        r#"select "table_b"."id", "table_b"."title" from "table_b""#,
+        statement
+            .repr()
+            .to_sql_string::<kosame::sql::postgres::Dialect>()
+            .unwrap()
+    );
+
+    let statement = pg_statement! {
+        select table_a.*, table_b.title
+        from schema::table_a
+            left join schema::table_b on table_b.id = table_a.id
+    };
+    assert_eq!(
+        r#"select "table_a"."id", "table_a"."content", "table_b"."title" from "table_a" left join "table_b" on "table_b"."id" = "table_a"."id""#,
+        statement
+            .repr()
+            .to_sql_string::<kosame::sql::postgres::Dialect>()
+            .unwrap()
+    );
+    check_row(&statement, |row| {
+        let _: i32 = row.id;
+        let _: Option<String> = row.content;
+        let _: Option<String> = row.title;
+    });
+
+    let statement = pg_statement! {
+        with numbered as (select table_a.*, 1 as one: i32 from schema::table_a)
+        select numbered.*, letters.* from numbered
+            inner join (values (1, "a")) as letters (number, letter) on letters.number = numbered.id
+    };
+    assert_eq!(
+        r#"with "numbered" as (select "table_a"."id", "table_a"."content", 1 as "one" from "table_a") select "numbered"."id", "numbered"."content", "numbered"."one", "letters"."number", "letters"."letter" from "numbered" inner join (values (1, 'a')) as "letters" ("number", "letter") on "letters"."number" = "numbered"."id""#,
         statement
             .repr()
             .to_sql_string::<kosame::sql::postgres::Dialect>()
             .unwrap()
     );
+    check_row(&statement, |row| {
+        let _: i32 = row.id;
+        let _: Option<String> = row.content;
+        let _: i32 = row.one;
+        let _: i32 = row.number;
+        let _: String = row.letter;
+    });
 }
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use proc_macro_error::emit_call_site_error;
+use proc_macro2::Span;
 use syn::{
     Ident, LitInt, LitStr, Path, Token, parenthesized,
     parse::{Parse, ParseStream},
@@ -27,9 +27,19 @@ pub enum MetaLocation {
     QueryInner,
     QueryOuter,
     StatementInner,
+    Row,
 }
 
 impl CustomMeta {
+    /// The driver declared by the `driver` meta argument. It is required in every location that
+    /// generates driver specific code, which is checked while parsing.
+    pub fn driver(&self) -> Driver {
+        self.driver
+            .as_ref()
+            .expect("driver should be checked during parsing")
+            .driver()
+    }
+
     pub fn parse_attrs(attrs: &[syn::Attribute], location: MetaLocation) -> syn::Result<Self> {
         let mut result = Self::default();
 
@@ -69,6 +79,7 @@ impl CustomMeta {
                                 location == MetaLocation::TableInner
                                     || location == MetaLocation::QueryInner
                                     || location == MetaLocation::StatementInner
+                                    || location == MetaLocation::Row
                             );
                         }
                         MetaItem::Rename(rename) => {
@@ -89,12 +100,16 @@ impl CustomMeta {
         }
 
         match location {
-            MetaLocation::TableInner | MetaLocation::QueryInner | MetaLocation::StatementInner
+            MetaLocation::TableInner
+            | MetaLocation::QueryInner
+            | MetaLocation::StatementInner
+            | MetaLocation::Row
                 if result.driver.is_none() =>
             {
-                emit_call_site_error!(
-                    "missing `driver` attribute, e.g. #[kosame(driver = \"tokio-postgres\")]"
-                );
+                return Err(syn::Error::new(
+                    Span::call_site(),
+                    "missing `driver` attribute, e.g. #[kosame(driver = \"tokio-postgres\")]",
+                ));
             }
             _ => {}
         }
@@ -136,7 +151,16 @@ impl Parse for MetaItem {
 pub struct MetaDriver {
     pub path: keyword::driver,
     pub _eq_token: Token![=],
-    pub _value: LitStr,
+    pub value: LitStr,
+}
+
+impl MetaDriver {
+    pub fn driver(&self) -> Driver {
+        self.value
+            .value()
+            .parse()
+            .expect("driver should be checked during parsing")
+    }
 }
 
 impl Parse for MetaDriver {
@@ -144,7 +168,7 @@ impl Parse for MetaDriver {
         Ok(Self {
             path: input.parse()?,
             _eq_token: input.parse()?,
-            _value: {
+            value: {
                 let value: LitStr = input.parse()?;
                 if value.value().parse::<Driver>().is_err() {
                     return Err(syn::Error::new(value.span(), "unknown driver value"));
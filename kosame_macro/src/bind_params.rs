@@ -1,44 +1,119 @@
-use crate::{expr, visitor::Visitor};
+use crate::{driver::Driver, expr, visitor::Visitor};
+use proc_macro_error::emit_error;
 use proc_macro2::TokenStream;
 use quote::{ToTokens, format_ident, quote};
 use syn::Ident;
 
 pub struct BindParamsBuilder<'a> {
     params: Vec<&'a Ident>,
+    arrays: Vec<&'a Ident>,
+    occurrences: Vec<(&'a Ident, bool)>,
 }
 
 impl BindParamsBuilder<'_> {
     pub fn new() -> Self {
-        Self { params: Vec::new() }
+        Self {
+            params: Vec::new(),
+            arrays: Vec::new(),
+            occurrences: Vec::new(),
+        }
     }
 }
 
 impl<'a> BindParamsBuilder<'a> {
-    pub fn build(self) -> BindParams<'a> {
-        BindParams::new(self.params)
+    pub fn build(self, driver: Driver) -> BindParams<'a> {
+        if !driver.supports_arrays() {
+            for (occurrence, is_array) in &self.occurrences {
+                if !is_array && self.arrays.contains(occurrence) {
+                    emit_error!(
+                        occurrence,
+                        "bind parameter `{}` holds an array and can only be used in `= any(...)` with the `{}` driver",
+                        occurrence,
+                        driver.as_str()
+                    );
+                }
+            }
+        }
+        BindParams::new(self.params, self.arrays, self.occurrences, driver)
+    }
+
+    fn add_param(&mut self, name: &'a Ident) {
+        if !self.params.contains(&name) {
+            self.params.push(name);
+        }
     }
 }
 
 impl<'a> Visitor<'a> for BindParamsBuilder<'a> {
     fn visit_bind_param(&mut self, bind_param: &'a expr::BindParam) {
-        if !self.params.contains(&&bind_param.name) {
-            self.params.push(&bind_param.name);
+        self.add_param(&bind_param.name);
+        self.occurrences.push((&bind_param.name, false));
+    }
+
+    fn visit_array_bind_param(&mut self, bind_param: &'a expr::BindParam) {
+        self.add_param(&bind_param.name);
+        if !self.arrays.contains(&&bind_param.name) {
+            self.arrays.push(&bind_param.name);
         }
+        self.occurrences.push((&bind_param.name, true));
     }
 }
 
 pub struct BindParams<'a> {
     params: Vec<&'a Ident>,
+    /// Bind parameters that hold an array, as in `= any(:ids)`.
+    arrays: Vec<&'a Ident>,
+    /// Every use of a bind parameter in the order the visitor encountered them, and whether it was
+    /// used as an array. Dialects with positional `?` placeholders bind values in this order, so
+    /// the visitor must walk the syntax tree in the same order the SQL is rendered.
+    occurrences: Vec<(&'a Ident, bool)>,
+    driver: Driver,
 }
 
 impl<'a> BindParams<'a> {
-    fn new(params: Vec<&'a Ident>) -> Self {
-        Self { params }
+    fn new(
+        params: Vec<&'a Ident>,
+        arrays: Vec<&'a Ident>,
+        occurrences: Vec<(&'a Ident, bool)>,
+        driver: Driver,
+    ) -> Self {
+        Self {
+            params,
+            arrays,
+            occurrences,
+            driver,
+        }
     }
 
     pub fn is_empty(&self) -> bool {
         self.params.is_empty()
     }
+
+    /// The number of elements of each array bind parameter, as an array of `(&str, usize)` to pass
+    /// to `Formatter::with_array_lengths`. This is `None` if the rendered SQL does not depend on
+    /// the values of the bind parameters.
+    pub fn array_lengths(&self) -> Option<TokenStream> {
+        if self.driver.supports_arrays() || self.arrays.is_empty() {
+            return None;
+        }
+
+        let array_trait = self.array_trait();
+        let names = &self.arrays;
+        let name_strings = self.arrays.iter().map(|name| name.to_string());
+        Some(quote! {
+            [#((#name_strings, #array_trait::len(self.params.#names))),*]
+        })
+    }
+
+    fn array_trait(&self) -> TokenStream {
+        match self.driver {
+            Driver::Postgres | Driver::TokioPostgres => {
+                quote! { ::kosame::driver::postgres_types::ToSql }
+            }
+            Driver::Rusqlite => quote! { ::kosame::driver::rusqlite::ToSqlArray },
+            Driver::Mysql => quote! { ::kosame::driver::mysql_types::ToValueArray },
+        }
+    }
 }
 
 impl ToTokens for BindParams<'_> {
@@ -54,10 +129,31 @@ impl ToTokens for BindParams<'_> {
             });
         }
 
+        // Postgres binds arrays like any other value, e.g. `Vec<i32>` as `int[]`.
+        let field_type = match self.driver {
+            Driver::Postgres | Driver::TokioPostgres => {
+                quote! { &'a (dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync) }
+            }
+            Driver::Rusqlite => quote! { &'a dyn ::kosame::driver::rusqlite::ToSql },
+            Driver::Mysql => quote! { &'a dyn ::kosame::driver::mysql_types::ToValue },
+        };
+        let array_field_type = match self.driver {
+            Driver::Postgres | Driver::TokioPostgres => field_type.clone(),
+            Driver::Rusqlite | Driver::Mysql => {
+                let array_trait = self.array_trait();
+                quote! { &'a dyn #array_trait }
+            }
+        };
+
         let mut fields = vec![];
         for name in &self.params {
+            let field_type = if self.arrays.contains(name) {
+                &array_field_type
+            } else {
+                &field_type
+            };
             fields.push(quote! {
-                #name: &'a (dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync)
+                #name: #field_type
             });
         }
         let fields_len = fields.len();
@@ -77,14 +173,62 @@ impl ToTokens for BindParams<'_> {
         }
         .to_tokens(tokens);
 
-        #[cfg(any(feature = "postgres", feature = "tokio-postgres"))]
-        quote! {
-            impl<'a> ::kosame::params::Params<Vec<&'a (dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync + 'a)>> for Params #lifetime {
-                fn to_driver(&self) -> Vec<&'a (dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync + 'a)> {
-                    vec![#(self.#field_names),*]
+        match self.driver {
+            Driver::Postgres | Driver::TokioPostgres => quote! {
+                impl<'a> ::kosame::params::Params<Vec<&'a (dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync + 'a)>> for Params #lifetime {
+                    fn to_driver(&self) -> Vec<&'a (dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync + 'a)> {
+                        vec![#(self.#field_names),*]
+                    }
+                }
+            },
+            Driver::Rusqlite => {
+                let (arrays, scalars): (Vec<&Ident>, Vec<&Ident>) = field_names
+                    .iter()
+                    .partition(|name| self.arrays.contains(name));
+                let scalar_names = scalars.iter().map(|name| format!(":{name}"));
+                let array_names = arrays.iter().map(|name| name.to_string());
+                quote! {
+                    impl<'a> ::kosame::params::Params<Vec<(::std::borrow::Cow<'a, str>, &'a (dyn ::kosame::driver::rusqlite::ToSql + 'a))>> for Params #lifetime {
+                        fn to_driver(&self) -> Vec<(::std::borrow::Cow<'a, str>, &'a (dyn ::kosame::driver::rusqlite::ToSql + 'a))> {
+                            #[allow(unused_mut)]
+                            let mut params = vec![#((::std::borrow::Cow::Borrowed(#scalar_names), self.#scalars)),*];
+                            #(
+                                for (index, element) in ::kosame::driver::rusqlite::ToSqlArray::elements(self.#arrays).into_iter().enumerate() {
+                                    let name = ::kosame::sql::array_element_name(#array_names, index);
+                                    params.push((::std::borrow::Cow::Owned(format!(":{name}")), element));
+                                }
+                            )*
+                            params
+                        }
+                    }
                 }
             }
-        }.to_tokens(tokens);
+            Driver::Mysql => {
+                // Each use of an array expands to one value per element.
+                let values = self.occurrences.iter().map(|(name, is_array)| {
+                    if *is_array {
+                        quote! {
+                            values.extend(::kosame::driver::mysql_types::ToValueArray::to_values(self.#name));
+                        }
+                    } else {
+                        quote! {
+                            values.push(::kosame::driver::mysql_types::ToValue::to_value(self.#name));
+                        }
+                    }
+                });
+                quote! {
+                    impl<'a> ::kosame::params::Params<Vec<::kosame::driver::mysql_types::Value>> for Params #lifetime {
+                        fn to_driver(&self) -> Vec<::kosame::driver::mysql_types::Value> {
+                            #[allow(unused_mut)]
+                            let mut values = Vec::new();
+                            #(#values)*
+                            values
+                        }
+                    }
+                }
+            }
+        }
+        .to_tokens(tokens);
     }
 }
 
@@ -0,0 +1,90 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{
+    Token, parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+use crate::{expr::Expr, keyword, stars::StarExpander, visitor::Visitor};
+
+pub struct Distinct {
+    pub _distinct: keyword::distinct,
+    pub on: Option<DistinctOn>,
+}
+
+impl Distinct {
+    pub fn parse_optional(input: ParseStream) -> syn::Result<Option<Self>> {
+        Self::peek(input).then(|| input.parse()).transpose()
+    }
+
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::distinct)
+    }
+
+    pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        visitor.visit_distinct(self);
+        if let Some(on) = &self.on {
+            for expr in &on.exprs {
+                expr.accept(visitor);
+            }
+        }
+    }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        if let Some(on) = &mut self.on {
+            for expr in &mut on.exprs {
+                expr.expand_stars(stars)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Parse for Distinct {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            _distinct: input.parse()?,
+            on: input.peek(keyword::on).then(|| input.parse()).transpose()?,
+        })
+    }
+}
+
+impl ToTokens for Distinct {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match &self.on {
+            Some(on) => {
+                let exprs = on.exprs.iter();
+                quote! { ::kosame::repr::clause::Distinct::On(&[#(#exprs),*]) }
+            }
+            None => quote! { ::kosame::repr::clause::Distinct::Rows },
+        }
+        .to_tokens(tokens);
+    }
+}
+
+pub struct DistinctOn {
+    pub _on: keyword::on,
+    pub _paren_token: syn::token::Paren,
+    pub exprs: Punctuated<Expr, Token![,]>,
+}
+
+impl Parse for DistinctOn {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let _on = input.parse()?;
+        let _paren_token = parenthesized!(content in input);
+        let exprs = content.parse_terminated(Expr::parse, Token![,])?;
+        if exprs.is_empty() {
+            return Err(syn::Error::new(
+                _paren_token.span.join(),
+                "distinct on expression list cannot be empty",
+            ));
+        }
+        Ok(Self {
+            _on,
+            _paren_token,
+            exprs,
+        })
+    }
+}
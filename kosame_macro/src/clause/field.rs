@@ -10,12 +10,13 @@ use syn::{
 use crate::{
     clause::peek_clause,
     correlations::{CorrelationId, Correlations},
-    expr::Expr,
+    expr::{ColumnRef, Correlation, Expr},
     inferred_type::{InferredType, resolve_type},
     part::{Alias, TypeOverride},
     quote_option::QuoteOption,
     row::RowField,
     scopes::{ScopeId, Scopes},
+    stars::{StarExpander, StarSource},
     visitor::Visitor,
 };
 
@@ -61,6 +62,10 @@ impl Field {
         self.expr.accept(visitor);
     }
 
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        self.expr.expand_stars(stars)
+    }
+
     pub fn infer_name(&self) -> Option<&Ident> {
         self.alias
             .as_ref()
@@ -98,11 +103,15 @@ impl ToTokens for Field {
     }
 }
 
-pub struct Fields(pub Punctuated<Field, Token![,]>);
+pub struct Fields {
+    pub fields: Punctuated<Field, Token![,]>,
+    /// The `*` and `table.*` items that have not been expanded into fields yet.
+    pub stars: Vec<FieldStar>,
+}
 
 impl Fields {
     pub fn iter(&self) -> impl Iterator<Item = &Field> {
-        self.0.iter()
+        self.fields.iter()
     }
 
     pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
@@ -114,32 +123,147 @@ impl Fields {
     pub fn columns(&self) -> Vec<&Ident> {
         self.iter().flat_map(|field| field.infer_name()).collect()
     }
+
+    /// Whether all `*` and `table.*` items have been expanded into the columns they select.
+    pub fn is_expanded(&self) -> bool {
+        self.stars.is_empty()
+    }
+
+    /// Replaces `*` with the columns of all given sources and `table.*` with the columns of the
+    /// source of that name, then expands the subqueries of the fields.
+    pub fn expand_stars(
+        &mut self,
+        sources: &[StarSource],
+        stars: &mut StarExpander,
+    ) -> syn::Result<()> {
+        self.expand_sources(sources)?;
+        for field in self.fields.iter_mut() {
+            field.expand_stars(stars)?;
+        }
+        Ok(())
+    }
+
+    /// Replaces the `*` and `table.*` items with the columns of the sources. If the columns of any
+    /// source are not known yet, nothing is expanded.
+    fn expand_sources(&mut self, sources: &[StarSource]) -> syn::Result<()> {
+        let mut expansions = Vec::with_capacity(self.stars.len());
+        for star in &self.stars {
+            let sources = match &star.table {
+                Some(table) => {
+                    let sources = sources
+                        .iter()
+                        .filter(|source| source.name == Some(&table.name))
+                        .collect::<Vec<_>>();
+                    if sources.is_empty() {
+                        return Err(syn::Error::new(
+                            table.name.span(),
+                            format!("no table named `{}` to select the columns of", table.name),
+                        ));
+                    }
+                    sources
+                }
+                None => {
+                    if sources.is_empty() {
+                        return Err(syn::Error::new(
+                            star.star_token.span,
+                            "`*` needs a table to select the columns of",
+                        ));
+                    }
+                    sources.iter().collect()
+                }
+            };
+
+            let mut fields = Vec::new();
+            for source in sources {
+                let Some(columns) = &source.columns else {
+                    return Ok(());
+                };
+                for column in columns {
+                    let mut name = column.clone();
+                    name.set_span(star.star_token.span);
+                    fields.push(Field {
+                        attrs: star.attrs.clone(),
+                        expr: Expr::ColumnRef(ColumnRef {
+                            correlation: source.name.map(|table| {
+                                let mut name = table.clone();
+                                name.set_span(star.star_token.span);
+                                Correlation {
+                                    name,
+                                    _period_token: Token![.](star.star_token.span),
+                                }
+                            }),
+                            name,
+                        }),
+                        alias: None,
+                        type_override: None,
+                    });
+                }
+            }
+            expansions.push((star.index, fields));
+        }
+
+        let mut expansions = expansions.into_iter().peekable();
+        let mut fields = Punctuated::new();
+        for (index, field) in std::mem::take(&mut self.fields).into_iter().enumerate() {
+            while let Some((_, expanded)) =
+                expansions.next_if(|(star_index, _)| *star_index == index)
+            {
+                fields.extend(expanded);
+            }
+            fields.push(field);
+        }
+        for (_, expanded) in expansions {
+            fields.extend(expanded);
+        }
+        self.fields = fields;
+        self.stars.clear();
+        Ok(())
+    }
 }
 
 impl Parse for Fields {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut fields = Punctuated::<Field, _>::new();
+        let mut stars = Vec::new();
 
         while !input.is_empty() {
             if peek_clause(input) {
                 break;
             }
 
-            fields.push(input.parse()?);
+            let fork = input.fork();
+            fork.call(Attribute::parse_outer)?;
+            let is_star = FieldStar::peek(&fork);
+            if is_star {
+                stars.push(FieldStar::parse(input, fields.len())?);
+            } else {
+                fields.push(input.parse()?);
+            }
 
             if !input.peek(Token![,]) {
                 break;
             }
-            fields.push_punct(input.parse()?);
+            let comma = input.parse()?;
+            if !is_star {
+                fields.push_punct(comma);
+            }
         }
 
-        Ok(Self(fields))
+        Ok(Self { fields, stars })
     }
 }
 
 impl ToTokens for Fields {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let fields = self.0.iter();
+        for star in &self.stars {
+            emit_error!(
+                star.star_token.span,
+                "the columns selected by `*` cannot be determined";
+                help = "consider listing the columns instead"
+            );
+        }
+
+        let fields = self.fields.iter();
         quote! {
             ::kosame::repr::clause::Fields::new(&[
                 #(#fields),*
@@ -148,3 +272,35 @@ impl ToTokens for Fields {
         .to_tokens(tokens)
     }
 }
+
+/// A `*` or `table.*` in a field list, which selects all columns of all tables or of one table.
+pub struct FieldStar {
+    pub attrs: Vec<Attribute>,
+    pub table: Option<Correlation>,
+    pub star_token: Token![*],
+    /// The number of fields before this item, which is where its columns are inserted.
+    pub index: usize,
+}
+
+impl FieldStar {
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(Token![*])
+            || (input.peek(Ident) && input.peek2(Token![.]) && input.peek3(Token![*]))
+    }
+
+    fn parse(input: ParseStream, index: usize) -> syn::Result<Self> {
+        Ok(Self {
+            attrs: input.call(Attribute::parse_outer)?,
+            table: if input.peek(Ident) {
+                Some(Correlation {
+                    name: input.parse()?,
+                    _period_token: input.parse()?,
+                })
+            } else {
+                None
+            },
+            star_token: input.parse()?,
+            index,
+        })
+    }
+}
@@ -6,14 +6,14 @@ use syn::{
 };
 
 use crate::{
-    clause::WithItem,
+    clause::{Values, WithItem},
     command::Command,
     correlations::CorrelationId,
-    expr::Expr,
+    expr::{Call, Expr},
     keyword,
     part::{TableAlias, TablePath},
     quote_option::QuoteOption,
-    scopes::ScopeId,
+    stars::StarExpander,
     visitor::Visitor,
 };
 
@@ -27,6 +27,10 @@ impl From {
         self.chain.accept(visitor);
     }
 
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        self.chain.expand_stars(stars)
+    }
+
     pub fn parse_optional(input: ParseStream) -> syn::Result<Option<Self>> {
         Self::peek(input).then(|| input.parse()).transpose()
     }
@@ -65,6 +69,14 @@ impl FromChain {
         }
     }
 
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        self.start.expand_stars(stars)?;
+        for combinator in &mut self.combinators {
+            combinator.expand_stars(stars)?;
+        }
+        Ok(())
+    }
+
     pub fn len(&self) -> usize {
         self.combinators.len() + 1
     }
@@ -134,6 +146,20 @@ pub enum FromItem {
         alias: Option<TableAlias>,
         correlation_id: CorrelationId,
     },
+    /// A set returning function like `generate_series(1, 10) as s (n)`.
+    Function {
+        call: Call,
+        alias: Option<TableAlias>,
+        correlation_id: CorrelationId,
+    },
+    /// A list of rows like `(values (1, 'a'), (2, 'b')) as v (id, name)`, which must name its
+    /// columns.
+    Values {
+        paren_token: syn::token::Paren,
+        values: Values,
+        alias: TableAlias,
+        correlation_id: CorrelationId,
+    },
 }
 
 impl FromItem {
@@ -145,6 +171,21 @@ impl FromItem {
             Self::Subquery { command, .. } => {
                 command.accept(visitor);
             }
+            Self::Function { call, .. } => {
+                call.accept(visitor);
+            }
+            Self::Values { values, .. } => {
+                values.accept(visitor);
+            }
+        }
+    }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        match self {
+            Self::Table { .. } => Ok(()),
+            Self::Subquery { command, .. } => command.expand_stars(stars),
+            Self::Function { call, .. } => call.expand_stars(stars),
+            Self::Values { values, .. } => values.expand_stars(stars),
         }
     }
 
@@ -161,6 +202,13 @@ impl FromItem {
                     .ident
             })),
             Self::Subquery { alias, .. } => alias.as_ref().map(|alias| &alias.name),
+            Self::Function { call, alias, .. } => Some(
+                alias
+                    .as_ref()
+                    .map(|alias| &alias.name)
+                    .unwrap_or(&call.function),
+            ),
+            Self::Values { alias, .. } => Some(&alias.name),
         }
     }
 
@@ -168,6 +216,8 @@ impl FromItem {
         match self {
             Self::Table { correlation_id, .. } => *correlation_id,
             Self::Subquery { correlation_id, .. } => *correlation_id,
+            Self::Function { correlation_id, .. } => *correlation_id,
+            Self::Values { correlation_id, .. } => *correlation_id,
         }
     }
 
@@ -191,6 +241,19 @@ impl FromItem {
                 .into_iter()
                 .flat_map(|fields| fields.columns())
                 .collect(),
+            // Without a column list, a function has a single column named like the from item.
+            Self::Function { alias, .. } => match alias {
+                Some(TableAlias {
+                    columns: Some(columns),
+                    ..
+                }) => columns.columns.iter().collect(),
+                _ => self.name().into_iter().collect(),
+            },
+            Self::Values { alias, .. } => alias
+                .columns
+                .iter()
+                .flat_map(|columns| columns.columns.iter())
+                .collect(),
         }
     }
 }
@@ -202,19 +265,47 @@ impl Parse for FromItem {
             .then(|| input.parse())
             .transpose()?;
         let lookahead = input.lookahead1();
-        if lookahead.peek(syn::token::Paren) {
+        if lookahead.peek(syn::token::Paren) && peek_values(input) {
+            let content;
+            let paren_token = parenthesized!(content in input);
+            let values = content.parse()?;
+            let alias = input.call(TableAlias::parse_optional)?;
+            let Some(
+                alias @ TableAlias {
+                    columns: Some(_), ..
+                },
+            ) = alias
+            else {
+                return Err(syn::Error::new(
+                    paren_token.span.join(),
+                    "values lists in from clauses must name their columns, e.g. `as v (id, name)`",
+                ));
+            };
+            Ok(Self::Values {
+                paren_token,
+                values,
+                alias,
+                correlation_id: CorrelationId::new(),
+            })
+        } else if lookahead.peek(syn::token::Paren) {
             let content;
             Ok(Self::Subquery {
                 lateral_keyword,
                 paren_token: parenthesized!(content in input),
                 command: content.parse()?,
+                alias: input.call(parse_alias)?,
+                correlation_id: CorrelationId::new(),
+            })
+        } else if Call::peek(input) {
+            Ok(Self::Function {
+                call: input.parse()?,
                 alias: input.call(TableAlias::parse_optional)?,
                 correlation_id: CorrelationId::new(),
             })
         } else if lookahead.peek(Ident) {
             Ok(Self::Table {
                 table_path: input.parse()?,
-                alias: input.call(TableAlias::parse_optional)?,
+                alias: input.call(parse_alias)?,
                 correlation_id: CorrelationId::new(),
             })
         } else {
@@ -223,25 +314,44 @@ impl Parse for FromItem {
     }
 }
 
+/// Whether the input starts with a parenthesized `values` list.
+fn peek_values(input: ParseStream) -> bool {
+    input
+        .cursor()
+        .group(proc_macro2::Delimiter::Parenthesis)
+        .and_then(|(content, ..)| content.ident())
+        .is_some_and(|(ident, _)| ident == "values")
+}
+
+/// Parses the alias of a table or subquery. Column aliases are only supported for functions,
+/// values lists and `with` items so far.
+fn parse_alias(input: ParseStream) -> syn::Result<Option<TableAlias>> {
+    let alias = input.call(TableAlias::parse_optional)?;
+    if let Some(TableAlias {
+        columns: Some(columns),
+        ..
+    }) = &alias
+    {
+        return Err(syn::Error::new(
+            columns._paren_token.span.join(),
+            "column aliases are currently unsupported",
+        ));
+    }
+    Ok(alias)
+}
+
 impl ToTokens for FromItem {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         match self {
             Self::Table {
                 table_path, alias, ..
             } => {
-                let table = alias.as_ref().map(|alias| &alias.name).unwrap_or(
-                    &table_path
-                        .as_path()
-                        .segments
-                        .last()
-                        .expect("paths cannot be empty")
-                        .ident,
-                );
+                // Refer to the table itself rather than to this from item, whose name is the alias.
+                let source_id = table_path.correlation_id;
                 let alias = QuoteOption::from(alias);
-                let scope_id = ScopeId::of_scope();
                 quote! {
                     ::kosame::repr::clause::FromItem::Table {
-                        table: scopes::#scope_id::tables::#table::TABLE_NAME,
+                        table: correlations::#source_id::TABLE_NAME,
                         alias: #alias,
                     }
                 }
@@ -262,6 +372,23 @@ impl ToTokens for FromItem {
                     }
                 }
             }
+            Self::Function { call, alias, .. } => {
+                let alias = QuoteOption::from(alias);
+                quote! {
+                    ::kosame::repr::clause::FromItem::Function {
+                        call: &#call,
+                        alias: #alias,
+                    }
+                }
+            }
+            Self::Values { values, alias, .. } => {
+                quote! {
+                    ::kosame::repr::clause::FromItem::Values {
+                        values: &#values,
+                        alias: #alias,
+                    }
+                }
+            }
         }
         .to_tokens(tokens);
     }
@@ -381,6 +508,17 @@ impl FromCombinator {
         }
     }
 
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        match self {
+            Self::Join { right, on, .. } => {
+                right.expand_stars(stars)?;
+                on.expr.expand_stars(stars)
+            }
+            Self::NaturalJoin { right, .. } => right.expand_stars(stars),
+            Self::CrossJoin { right, .. } => right.expand_stars(stars),
+        }
+    }
+
     pub fn peek(input: ParseStream) -> bool {
         JoinType::peek(input) || input.peek(keyword::natural) || input.peek(keyword::cross)
     }
@@ -0,0 +1,159 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{
+    Ident, Token,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+use crate::{keyword, scopes::ScopeId, visitor::Visitor};
+
+pub struct Lock {
+    pub _for: Token![for],
+    pub strength: LockStrength,
+    pub of: Option<LockOf>,
+    pub wait: Option<LockWait>,
+}
+
+impl Lock {
+    pub fn parse_optional(input: ParseStream) -> syn::Result<Option<Self>> {
+        Self::peek(input).then(|| input.parse()).transpose()
+    }
+
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(Token![for])
+    }
+
+    pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        visitor.visit_lock(self);
+    }
+}
+
+impl Parse for Lock {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            _for: input.parse()?,
+            strength: input.parse()?,
+            of: input.peek(keyword::of).then(|| input.parse()).transpose()?,
+            wait: (input.peek(keyword::nowait) || input.peek(keyword::skip))
+                .then(|| input.parse())
+                .transpose()?,
+        })
+    }
+}
+
+impl ToTokens for Lock {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let strength = &self.strength;
+        let scope_id = ScopeId::of_scope();
+        let of = self.of.iter().flat_map(|of| of.tables.iter());
+        let wait = match &self.wait {
+            Some(LockWait::Nowait(..)) => quote! {
+                ::core::option::Option::Some(::kosame::repr::clause::LockWait::Nowait)
+            },
+            Some(LockWait::SkipLocked(..)) => quote! {
+                ::core::option::Option::Some(::kosame::repr::clause::LockWait::SkipLocked)
+            },
+            None => quote! { ::core::option::Option::None },
+        };
+        quote! {
+            ::kosame::repr::clause::Lock::new(
+                #strength,
+                &[#(scopes::#scope_id::tables::#of::TABLE_NAME),*],
+                #wait,
+            )
+        }
+        .to_tokens(tokens);
+    }
+}
+
+#[allow(unused)]
+pub enum LockStrength {
+    Update(keyword::update),
+    NoKeyUpdate(keyword::no, keyword::key, keyword::update),
+    Share(keyword::share),
+    KeyShare(keyword::key, keyword::share),
+}
+
+impl LockStrength {
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Update(update) => update.span,
+            Self::NoKeyUpdate(no, ..) => no.span,
+            Self::Share(share) => share.span,
+            Self::KeyShare(key, ..) => key.span,
+        }
+    }
+}
+
+impl Parse for LockStrength {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(keyword::update) {
+            Ok(Self::Update(input.parse()?))
+        } else if lookahead.peek(keyword::no) {
+            Ok(Self::NoKeyUpdate(
+                input.parse()?,
+                input.parse()?,
+                input.parse()?,
+            ))
+        } else if lookahead.peek(keyword::share) {
+            Ok(Self::Share(input.parse()?))
+        } else if lookahead.peek(keyword::key) {
+            Ok(Self::KeyShare(input.parse()?, input.parse()?))
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+impl ToTokens for LockStrength {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Update(..) => quote! { ::kosame::repr::clause::LockStrength::Update },
+            Self::NoKeyUpdate(..) => quote! { ::kosame::repr::clause::LockStrength::NoKeyUpdate },
+            Self::Share(..) => quote! { ::kosame::repr::clause::LockStrength::Share },
+            Self::KeyShare(..) => quote! { ::kosame::repr::clause::LockStrength::KeyShare },
+        }
+        .to_tokens(tokens);
+    }
+}
+
+pub struct LockOf {
+    pub _of: keyword::of,
+    pub tables: Punctuated<Ident, Token![,]>,
+}
+
+impl Parse for LockOf {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let _of = input.parse()?;
+        let mut tables = Punctuated::new();
+        loop {
+            tables.push(input.parse()?);
+            if !input.peek(Token![,]) {
+                break;
+            }
+            tables.push_punct(input.parse()?);
+        }
+        Ok(Self { _of, tables })
+    }
+}
+
+#[allow(unused)]
+pub enum LockWait {
+    Nowait(keyword::nowait),
+    SkipLocked(keyword::skip, keyword::locked),
+}
+
+impl Parse for LockWait {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(keyword::nowait) {
+            Ok(Self::Nowait(input.parse()?))
+        } else if lookahead.peek(keyword::skip) {
+            Ok(Self::SkipLocked(input.parse()?, input.parse()?))
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
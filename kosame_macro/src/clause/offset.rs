@@ -2,7 +2,7 @@ use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 use syn::parse::{Parse, ParseStream};
 
-use crate::{expr::Expr, keyword, visitor::Visitor};
+use crate::{expr::Expr, keyword, stars::StarExpander, visitor::Visitor};
 
 pub struct Offset {
     pub _offset: keyword::offset,
@@ -21,6 +21,10 @@ impl Offset {
     pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
         self.expr.accept(visitor);
     }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        self.expr.expand_stars(stars)
+    }
 }
 
 impl Parse for Offset {
@@ -0,0 +1,150 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{
+    Ident, Token,
+    parse::{Parse, ParseStream},
+};
+
+use crate::{
+    clause::{Set, Where},
+    correlations::CorrelationId,
+    keyword,
+    part::ColumnList,
+    quote_option::QuoteOption,
+    stars::StarExpander,
+    visitor::Visitor,
+};
+
+pub struct OnConflict {
+    pub _on_keyword: keyword::on,
+    pub _conflict_keyword: keyword::conflict,
+    pub target: Option<ColumnList>,
+    pub _do_token: Token![do],
+    pub action: ConflictAction,
+    /// The `excluded` pseudo-table holding the row that failed to insert.
+    pub excluded: Ident,
+    pub excluded_correlation_id: CorrelationId,
+}
+
+impl OnConflict {
+    pub fn parse_optional(input: ParseStream) -> syn::Result<Option<Self>> {
+        Self::peek(input).then(|| input.parse()).transpose()
+    }
+
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::on) && input.peek2(keyword::conflict)
+    }
+
+    pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        self.action.accept(visitor);
+    }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        self.action.expand_stars(stars)
+    }
+}
+
+impl Parse for OnConflict {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            _on_keyword: input.parse()?,
+            _conflict_keyword: input.parse()?,
+            target: input
+                .peek(syn::token::Paren)
+                .then(|| input.parse())
+                .transpose()?,
+            _do_token: input.parse()?,
+            action: input.parse()?,
+            excluded: Ident::new("excluded", Span::call_site()),
+            excluded_correlation_id: CorrelationId::new(),
+        })
+    }
+}
+
+impl ToTokens for OnConflict {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let target = QuoteOption::from(&self.target);
+        let action = &self.action;
+        quote! {
+            ::kosame::repr::clause::OnConflict::new(#target, #action)
+        }
+        .to_tokens(tokens);
+    }
+}
+
+pub enum ConflictAction {
+    Nothing {
+        _nothing_keyword: keyword::nothing,
+    },
+    Update {
+        _update_keyword: keyword::update,
+        set: Set,
+        r#where: Option<Where>,
+    },
+}
+
+impl ConflictAction {
+    pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        match self {
+            Self::Nothing { .. } => {}
+            Self::Update { set, r#where, .. } => {
+                set.accept(visitor);
+                if let Some(inner) = r#where {
+                    inner.accept(visitor);
+                }
+            }
+        }
+    }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        match self {
+            Self::Nothing { .. } => Ok(()),
+            Self::Update { set, r#where, .. } => {
+                set.expand_stars(stars)?;
+                if let Some(inner) = r#where {
+                    inner.expand_stars(stars)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Parse for ConflictAction {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(keyword::nothing) {
+            Ok(Self::Nothing {
+                _nothing_keyword: input.parse()?,
+            })
+        } else if lookahead.peek(keyword::update) {
+            Ok(Self::Update {
+                _update_keyword: input.parse()?,
+                set: input.parse()?,
+                r#where: input.call(Where::parse_optional)?,
+            })
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+impl ToTokens for ConflictAction {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Nothing { .. } => quote! {
+                ::kosame::repr::clause::ConflictAction::Nothing
+            },
+            Self::Update { set, r#where, .. } => {
+                let r#where = QuoteOption::from(r#where);
+                quote! {
+                    ::kosame::repr::clause::ConflictAction::Update {
+                        set: #set,
+                        r#where: #r#where,
+                    }
+                }
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
@@ -6,7 +6,7 @@ use syn::{
     punctuated::Punctuated,
 };
 
-use crate::{clause::peek_clause, expr::Expr, keyword, visitor::Visitor};
+use crate::{clause::peek_clause, expr::Expr, keyword, stars::StarExpander, visitor::Visitor};
 
 pub struct OrderBy {
     pub _order: keyword::order,
@@ -28,6 +28,13 @@ impl OrderBy {
             item.expr.accept(visitor);
         }
     }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        for item in &mut self.items {
+            item.expr.expand_stars(stars)?;
+        }
+        Ok(())
+    }
 }
 
 impl Parse for OrderBy {
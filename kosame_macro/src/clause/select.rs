@@ -2,10 +2,17 @@ use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 use syn::parse::{Parse, ParseStream};
 
-use crate::{clause::Fields, keyword, visitor::Visitor};
+use crate::{
+    clause::{Distinct, Fields},
+    keyword,
+    quote_option::QuoteOption,
+    stars::{StarExpander, StarSource},
+    visitor::Visitor,
+};
 
 pub struct Select {
     pub _select: keyword::select,
+    pub distinct: Option<Distinct>,
     pub fields: Fields,
 }
 
@@ -15,14 +22,30 @@ impl Select {
     }
 
     pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        if let Some(inner) = &self.distinct {
+            inner.accept(visitor);
+        }
         self.fields.accept(visitor);
     }
+
+    /// Expands `*` in the fields into the columns of the given sources.
+    pub fn expand_stars(
+        &mut self,
+        sources: &[StarSource],
+        stars: &mut StarExpander,
+    ) -> syn::Result<()> {
+        if let Some(inner) = &mut self.distinct {
+            inner.expand_stars(stars)?;
+        }
+        self.fields.expand_stars(sources, stars)
+    }
 }
 
 impl Parse for Select {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         Ok(Self {
             _select: input.call(keyword::select::parse_autocomplete)?,
+            distinct: input.call(Distinct::parse_optional)?,
             fields: input.parse()?,
         })
     }
@@ -30,9 +53,10 @@ impl Parse for Select {
 
 impl ToTokens for Select {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let distinct = QuoteOption::from(&self.distinct);
         let fields = &self.fields;
         quote! {
-            ::kosame::repr::clause::Select::new(#fields)
+            ::kosame::repr::clause::Select::new(#distinct, #fields)
         }
         .to_tokens(tokens)
     }
@@ -6,7 +6,7 @@ use syn::{
     punctuated::Punctuated,
 };
 
-use crate::{clause::peek_clause, expr::Expr, keyword, visitor::Visitor};
+use crate::{clause::peek_clause, expr::Expr, keyword, stars::StarExpander, visitor::Visitor};
 
 pub struct Set {
     _set_keyword: keyword::set,
@@ -23,6 +23,13 @@ impl Set {
             item.accept(visitor);
         }
     }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        for item in &mut self.items {
+            item.expand_stars(stars)?;
+        }
+        Ok(())
+    }
 }
 
 impl Parse for Set {
@@ -82,6 +89,13 @@ impl SetItem {
             }
         }
     }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        match self {
+            Self::Default { .. } => Ok(()),
+            Self::Expr { expr, .. } => expr.expand_stars(stars),
+        }
+    }
 }
 
 impl Parse for SetItem {
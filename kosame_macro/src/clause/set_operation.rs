@@ -0,0 +1,110 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::parse::{Parse, ParseStream};
+
+use crate::{
+    command::{Command, CommandType, Select},
+    correlations::CorrelationId,
+    keyword,
+    scopes::ScopeId,
+    stars::StarExpander,
+    visitor::Visitor,
+};
+
+pub struct SetOperation {
+    pub operator: SetOperator,
+    pub all: Option<keyword::all>,
+    /// The right-hand side of the set operation. It has its own scope, like a subquery.
+    pub command: Box<Command>,
+}
+
+impl SetOperation {
+    pub fn parse_many(input: ParseStream) -> syn::Result<Vec<Self>> {
+        let mut set_operations = Vec::new();
+        while Self::peek(input) {
+            set_operations.push(input.parse()?);
+        }
+        Ok(set_operations)
+    }
+
+    pub fn peek(input: ParseStream) -> bool {
+        SetOperator::peek(input)
+    }
+
+    pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        self.command.accept(visitor);
+    }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        self.command.expand_stars(stars)
+    }
+}
+
+impl Parse for SetOperation {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            operator: input.parse()?,
+            all: input
+                .peek(keyword::all)
+                .then(|| input.parse())
+                .transpose()?,
+            command: Box::new(Command {
+                attrs: Vec::new(),
+                with: None,
+                command_type: CommandType::Select(Box::new(input.call(Select::parse_core)?)),
+                correlation_id: CorrelationId::new(),
+                scope_id: ScopeId::new(),
+            }),
+        })
+    }
+}
+
+impl ToTokens for SetOperation {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let operator = &self.operator;
+        let all = self.all.is_some();
+        let command = &self.command;
+        quote! {
+            ::kosame::repr::clause::SetOperation::new(#operator, #all, &#command)
+        }
+        .to_tokens(tokens);
+    }
+}
+
+pub enum SetOperator {
+    Union(keyword::union),
+    Intersect(keyword::intersect),
+    Except(keyword::except),
+}
+
+impl SetOperator {
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::union) || input.peek(keyword::intersect) || input.peek(keyword::except)
+    }
+}
+
+impl Parse for SetOperator {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(keyword::union) {
+            Ok(Self::Union(input.parse()?))
+        } else if lookahead.peek(keyword::intersect) {
+            Ok(Self::Intersect(input.parse()?))
+        } else if lookahead.peek(keyword::except) {
+            Ok(Self::Except(input.parse()?))
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+impl ToTokens for SetOperator {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Union(..) => quote! { ::kosame::repr::clause::SetOperator::Union },
+            Self::Intersect(..) => quote! { ::kosame::repr::clause::SetOperator::Intersect },
+            Self::Except(..) => quote! { ::kosame::repr::clause::SetOperator::Except },
+        }
+        .to_tokens(tokens);
+    }
+}
@@ -6,7 +6,7 @@ use syn::{
     punctuated::Punctuated,
 };
 
-use crate::{expr::Expr, keyword, visitor::Visitor};
+use crate::{expr::Expr, keyword, stars::StarExpander, visitor::Visitor};
 
 pub struct Values {
     pub _values_keyword: keyword::values,
@@ -23,6 +23,13 @@ impl Values {
             row.accept(visitor);
         }
     }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        for item in &mut self.rows {
+            item.expand_stars(stars)?;
+        }
+        Ok(())
+    }
 }
 
 impl Parse for Values {
@@ -55,8 +62,8 @@ impl ToTokens for Values {
 }
 
 pub struct ValuesRow {
-    _paren_token: syn::token::Paren,
-    items: Punctuated<ValuesItem, Token![,]>,
+    pub _paren_token: syn::token::Paren,
+    pub items: Punctuated<ValuesItem, Token![,]>,
 }
 
 impl ValuesRow {
@@ -65,6 +72,13 @@ impl ValuesRow {
             item.accept(visitor);
         }
     }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        for item in &mut self.items {
+            item.expand_stars(stars)?;
+        }
+        Ok(())
+    }
 }
 
 impl Parse for ValuesRow {
@@ -97,6 +111,13 @@ impl ValuesItem {
             Self::Expr(expr) => expr.accept(visitor),
         }
     }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        match self {
+            Self::Default(..) => Ok(()),
+            Self::Expr(expr) => expr.expand_stars(stars),
+        }
+    }
 }
 
 impl Parse for ValuesItem {
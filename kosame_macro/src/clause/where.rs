@@ -1,11 +1,15 @@
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 use syn::{
-    Token,
+    Ident, Token,
     parse::{Parse, ParseStream},
 };
 
-use crate::{expr::Expr, visitor::Visitor};
+use crate::{
+    expr::{BinOp, Binary, Expr, Lit},
+    stars::StarExpander,
+    visitor::Visitor,
+};
 
 pub struct Where {
     pub _where: Token![where],
@@ -24,6 +28,49 @@ impl Where {
     pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
         self.expr.accept(visitor);
     }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        self.expr.expand_stars(stars)
+    }
+
+    /// The qualified columns that are checked with `is not null` in this clause, and therefore
+    /// cannot be `null` in the selected rows.
+    ///
+    /// Only checks of the form `table.column is not null` are found, and only if they are the
+    /// whole condition or an operand of a top-level chain of `and`s, possibly parenthesized. A
+    /// check under `or` or `not`, of an unqualified column or of any other expression does not
+    /// narrow the column, even where it would be sound to do so.
+    pub fn not_null_columns(&self) -> Vec<(&Ident, &Ident)> {
+        fn collect<'a>(expr: &'a Expr, columns: &mut Vec<(&'a Ident, &'a Ident)>) {
+            match expr {
+                Expr::Paren(paren) => collect(&paren.expr, columns),
+                Expr::Binary(Binary {
+                    lhs,
+                    op: BinOp::And(_),
+                    rhs,
+                }) => {
+                    collect(lhs, columns);
+                    collect(rhs, columns);
+                }
+                Expr::Binary(Binary {
+                    lhs,
+                    op: BinOp::IsNot(..),
+                    rhs,
+                }) if matches!(**rhs, Expr::Lit(Lit::Null(_))) => {
+                    if let Expr::ColumnRef(column_ref) = &**lhs
+                        && let Some(correlation) = &column_ref.correlation
+                    {
+                        columns.push((&correlation.name, &column_ref.name));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut columns = Vec::new();
+        collect(&self.expr, &mut columns);
+        columns
+    }
 }
 
 impl Parse for Where {
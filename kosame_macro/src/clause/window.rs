@@ -0,0 +1,232 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{
+    Ident, Token, parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+use crate::{
+    clause::{OrderBy, peek_clause},
+    expr::Expr,
+    keyword,
+    quote_option::QuoteOption,
+    stars::StarExpander,
+    visitor::Visitor,
+};
+
+pub struct Window {
+    pub _window: keyword::window,
+    pub items: Punctuated<WindowItem, Token![,]>,
+}
+
+impl Window {
+    pub fn parse_optional(input: ParseStream) -> syn::Result<Option<Self>> {
+        Self::peek(input).then(|| input.parse()).transpose()
+    }
+
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::window) && input.peek2(Ident)
+    }
+
+    pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        for item in &self.items {
+            item.definition.accept(visitor);
+        }
+    }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        for item in &mut self.items {
+            item.definition.expand_stars(stars)?;
+        }
+        Ok(())
+    }
+}
+
+impl Parse for Window {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            _window: input.call(keyword::window::parse_autocomplete)?,
+            items: {
+                let mut punctuated = Punctuated::new();
+                while !input.is_empty() {
+                    if peek_clause(input) {
+                        break;
+                    }
+                    punctuated.push(input.parse()?);
+                    if !input.peek(Token![,]) {
+                        break;
+                    }
+                    punctuated.push_punct(input.parse()?);
+                }
+                if punctuated.is_empty() {
+                    return Err(syn::Error::new(
+                        input.span(),
+                        "window clause cannot be empty",
+                    ));
+                }
+                punctuated
+            },
+        })
+    }
+}
+
+impl ToTokens for Window {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let items = self.items.iter();
+        quote! { ::kosame::repr::clause::Window::new(&[#(#items),*]) }.to_tokens(tokens)
+    }
+}
+
+pub struct WindowItem {
+    pub name: Ident,
+    pub _as: Token![as],
+    pub definition: WindowDefinition,
+}
+
+impl Parse for WindowItem {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            name: input.parse()?,
+            _as: input.parse()?,
+            definition: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for WindowItem {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = self.name.to_string();
+        let definition = &self.definition;
+        quote! { ::kosame::repr::clause::WindowItem::new(#name, #definition) }.to_tokens(tokens)
+    }
+}
+
+/// The parenthesized definition of a window, e.g. `(partition by posts.id order by comments.id)`.
+/// It may start with the name of a window from the `window` clause, which it then extends.
+pub struct WindowDefinition {
+    pub _paren: syn::token::Paren,
+    pub existing: Option<Ident>,
+    pub partition_by: Option<PartitionBy>,
+    pub order_by: Option<OrderBy>,
+}
+
+impl WindowDefinition {
+    pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        if let Some(inner) = &self.partition_by {
+            inner.accept(visitor);
+        }
+        if let Some(inner) = &self.order_by {
+            inner.accept(visitor);
+        }
+    }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        if let Some(inner) = &mut self.partition_by {
+            inner.expand_stars(stars)?;
+        }
+        if let Some(inner) = &mut self.order_by {
+            inner.expand_stars(stars)?;
+        }
+        Ok(())
+    }
+}
+
+impl Parse for WindowDefinition {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let _paren = parenthesized!(content in input);
+        let existing =
+            (content.peek(Ident) && !PartitionBy::peek(&content) && !OrderBy::peek(&content))
+                .then(|| content.parse())
+                .transpose()?;
+        let partition_by = content.call(PartitionBy::parse_optional)?;
+        let order_by = content.call(OrderBy::parse_optional)?;
+        if !content.is_empty() {
+            return Err(content.error("expected `partition by`, `order by` or `)`"));
+        }
+        Ok(Self {
+            _paren,
+            existing,
+            partition_by,
+            order_by,
+        })
+    }
+}
+
+impl ToTokens for WindowDefinition {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let existing = QuoteOption(self.existing.as_ref().map(Ident::to_string));
+        let partition_by = self
+            .partition_by
+            .iter()
+            .flat_map(|inner| inner.items.iter());
+        let order_by = QuoteOption::from(&self.order_by);
+        quote! {
+            ::kosame::repr::clause::WindowDefinition::new(
+                #existing,
+                &[#(#partition_by),*],
+                #order_by,
+            )
+        }
+        .to_tokens(tokens)
+    }
+}
+
+pub struct PartitionBy {
+    pub _partition: keyword::partition,
+    pub _by: keyword::by,
+    pub items: Punctuated<Expr, Token![,]>,
+}
+
+impl PartitionBy {
+    pub fn parse_optional(input: ParseStream) -> syn::Result<Option<Self>> {
+        Self::peek(input).then(|| input.parse()).transpose()
+    }
+
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::partition) && input.peek2(keyword::by)
+    }
+
+    pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        for item in &self.items {
+            item.accept(visitor);
+        }
+    }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        for item in &mut self.items {
+            item.expand_stars(stars)?;
+        }
+        Ok(())
+    }
+}
+
+impl Parse for PartitionBy {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            _partition: input.call(keyword::partition::parse_autocomplete)?,
+            _by: input.call(keyword::by::parse_autocomplete)?,
+            items: {
+                let mut punctuated = Punctuated::new();
+                while !input.is_empty() {
+                    if peek_clause(input) {
+                        break;
+                    }
+                    punctuated.push(input.parse()?);
+                    if !input.peek(Token![,]) {
+                        break;
+                    }
+                    punctuated.push_punct(input.parse()?);
+                }
+                if punctuated.is_empty() {
+                    return Err(syn::Error::new(
+                        input.span(),
+                        "partition by clause cannot be empty",
+                    ));
+                }
+                punctuated
+            },
+        })
+    }
+}
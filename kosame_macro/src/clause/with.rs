@@ -11,11 +11,13 @@ use crate::{
     correlations::CorrelationId,
     keyword,
     part::TableAlias,
+    stars::StarExpander,
     visitor::Visitor,
 };
 
 pub struct With {
     pub _with_keyword: keyword::with,
+    pub recursive: Option<keyword::recursive>,
     pub items: Punctuated<WithItem, Token![,]>,
 }
 
@@ -33,12 +35,30 @@ impl With {
             item.accept(visitor);
         }
     }
+
+    /// Expands the items in order, making each visible to the items after it.
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        for item in &mut self.items {
+            // The items of a recursive with clause may refer to themselves.
+            if self.recursive.is_some() {
+                stars.push_with_item(item.alias.name.clone(), None);
+            }
+            item.expand_stars(stars)?;
+            stars.push_with_item(
+                item.alias.name.clone(),
+                item.is_expanded()
+                    .then(|| item.columns().into_iter().cloned().collect()),
+            );
+        }
+        Ok(())
+    }
 }
 
 impl Parse for With {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         Ok(Self {
             _with_keyword: input.call(keyword::with::parse_autocomplete)?,
+            recursive: input.parse()?,
             items: {
                 let mut punctuated = Punctuated::new();
                 while !input.is_empty() {
@@ -62,8 +82,9 @@ impl Parse for With {
 
 impl ToTokens for With {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let recursive = self.recursive.is_some();
         let items = self.items.iter();
-        quote! { ::kosame::repr::clause::With::new(&[#(#items),*]) }.to_tokens(tokens)
+        quote! { ::kosame::repr::clause::With::new(#recursive, &[#(#items),*]) }.to_tokens(tokens)
     }
 }
 
@@ -80,6 +101,20 @@ impl WithItem {
         self.command.accept(visitor);
     }
 
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        self.command.expand_stars(stars)
+    }
+
+    /// Whether the columns of this item are known, which they are not while `*` in its command
+    /// has not been expanded yet.
+    pub fn is_expanded(&self) -> bool {
+        self.alias.columns.is_some()
+            || self
+                .command
+                .fields()
+                .is_none_or(|fields| fields.is_expanded())
+    }
+
     pub fn columns(&self) -> Vec<&Ident> {
         match &self.alias.columns {
             Some(columns) => columns.columns.iter().collect(),
@@ -2,7 +2,14 @@ use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 use syn::parse::{Parse, ParseStream};
 
-use crate::{clause::*, keyword, part::TargetTable, quote_option::QuoteOption, visitor::Visitor};
+use crate::{
+    clause::*,
+    keyword,
+    part::TargetTable,
+    quote_option::QuoteOption,
+    stars::{StarExpander, StarSource},
+    visitor::Visitor,
+};
 
 pub struct Delete {
     pub _delete_keyword: keyword::delete,
@@ -30,6 +37,27 @@ impl Delete {
             inner.accept(visitor)
         }
     }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        if let Some(inner) = &mut self.using {
+            inner.expand_stars(stars)?;
+        }
+        if let Some(inner) = &mut self.r#where {
+            inner.expand_stars(stars)?;
+        }
+        if let Some(returning) = &mut self.returning {
+            let sources = std::iter::once(StarSource::target_table(&self.target_table, stars))
+                .chain(
+                    self.using
+                        .iter()
+                        .flat_map(|using| &using.chain)
+                        .map(|from_item| StarSource::from_item(from_item, stars)),
+                )
+                .collect::<Vec<_>>();
+            returning.fields.expand_stars(&sources, stars)?;
+        }
+        Ok(())
+    }
 }
 
 impl Parse for Delete {
@@ -81,6 +109,10 @@ impl Using {
     pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
         self.chain.accept(visitor);
     }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        self.chain.expand_stars(stars)
+    }
 }
 
 impl Parse for Using {
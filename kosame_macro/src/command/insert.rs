@@ -2,13 +2,24 @@ use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 use syn::parse::{Parse, ParseStream};
 
-use crate::{clause::*, keyword, part::TargetTable, quote_option::QuoteOption, visitor::Visitor};
+use crate::{
+    clause::*,
+    command::Command,
+    keyword,
+    part::{ColumnList, TargetTable},
+    quote_option::QuoteOption,
+    scopes::ScopeId,
+    stars::{StarExpander, StarSource},
+    visitor::Visitor,
+};
 
 pub struct Insert {
     pub _insert_keyword: keyword::insert,
     pub _into_keyword: keyword::into,
     pub target_table: TargetTable,
-    pub values: Values,
+    pub columns: Option<ColumnList>,
+    pub source: InsertSource,
+    pub on_conflict: Option<OnConflict>,
     pub returning: Option<Returning>,
 }
 
@@ -18,12 +29,28 @@ impl Insert {
     }
 
     pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        visitor.visit_insert(self);
         self.target_table.accept(visitor);
-        self.values.accept(visitor);
+        self.source.accept(visitor);
+        if let Some(inner) = &self.on_conflict {
+            inner.accept(visitor)
+        }
         if let Some(inner) = &self.returning {
             inner.accept(visitor)
         }
     }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        self.source.expand_stars(stars)?;
+        if let Some(inner) = &mut self.on_conflict {
+            inner.expand_stars(stars)?;
+        }
+        if let Some(returning) = &mut self.returning {
+            let sources = [StarSource::target_table(&self.target_table, stars)];
+            returning.fields.expand_stars(&sources, stars)?;
+        }
+        Ok(())
+    }
 }
 
 impl Parse for Insert {
@@ -32,7 +59,12 @@ impl Parse for Insert {
             _insert_keyword: input.parse()?,
             _into_keyword: input.parse()?,
             target_table: input.parse()?,
-            values: input.parse()?,
+            columns: input
+                .peek(syn::token::Paren)
+                .then(|| input.parse())
+                .transpose()?,
+            source: input.parse()?,
+            on_conflict: input.call(OnConflict::parse_optional)?,
             returning: input.call(Returning::parse_optional)?,
         })
     }
@@ -41,19 +73,98 @@ impl Parse for Insert {
 impl ToTokens for Insert {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let target_table = &self.target_table;
-        let values = &self.values;
+        let columns = QuoteOption(self.columns.as_ref().map(|columns| {
+            // Refer to the columns through the `columns` module of the table, so that unknown
+            // columns are reported at compile time.
+            let scope_id = ScopeId::of_scope();
+            let table = self.target_table.name();
+            let columns = columns.columns.iter();
+            quote! {
+                ::kosame::repr::part::ColumnList::new(&[
+                    #(scopes::#scope_id::tables::#table::columns::#columns::COLUMN_NAME),*
+                ])
+            }
+        }));
+        let source = &self.source;
+        let on_conflict = QuoteOption::from(&self.on_conflict);
         let returning = QuoteOption::from(&self.returning);
 
         quote! {
             ::kosame::repr::command::Insert::new(
                 #target_table,
-                {
-                    mod scope {}
-                    #values
-                },
+                #columns,
+                #source,
+                #on_conflict,
                 #returning,
             )
         }
         .to_tokens(tokens);
     }
 }
+
+pub enum InsertSource {
+    Values(Values),
+    Select(Box<Command>),
+    DefaultValues {
+        _default_keyword: keyword::default,
+        _values_keyword: keyword::values,
+    },
+}
+
+impl InsertSource {
+    pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        match self {
+            Self::Values(values) => values.accept(visitor),
+            Self::Select(command) => command.accept(visitor),
+            Self::DefaultValues { .. } => {}
+        }
+    }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        match self {
+            Self::Values(values) => values.expand_stars(stars),
+            Self::Select(command) => command.expand_stars(stars),
+            Self::DefaultValues { .. } => Ok(()),
+        }
+    }
+}
+
+impl Parse for InsertSource {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(keyword::default) {
+            Ok(Self::DefaultValues {
+                _default_keyword: input.parse()?,
+                _values_keyword: input.parse()?,
+            })
+        } else if Values::peek(input) {
+            Ok(Self::Values(input.parse()?))
+        } else if With::peek(input) || Select::peek(input) {
+            Ok(Self::Select(input.parse()?))
+        } else {
+            Err(syn::Error::new(
+                input.span(),
+                "expected `values`, `select` or `default values`",
+            ))
+        }
+    }
+}
+
+impl ToTokens for InsertSource {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Values(values) => quote! {
+                ::kosame::repr::command::InsertSource::Values({
+                    mod scope {}
+                    #values
+                })
+            },
+            Self::Select(command) => quote! {
+                ::kosame::repr::command::InsertSource::Select(&#command)
+            },
+            Self::DefaultValues { .. } => quote! {
+                ::kosame::repr::command::InsertSource::DefaultValues
+            },
+        }
+        .to_tokens(tokens);
+    }
+}
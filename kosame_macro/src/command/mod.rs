@@ -16,13 +16,14 @@ pub use select::*;
 pub use update::*;
 
 use crate::{
-    clause::{Fields, FromChain, With},
+    clause::{Fields, FromChain, GroupBy, OnConflict, Where, With},
     correlations::CorrelationId,
     keyword,
     part::TargetTable,
     quote_option::QuoteOption,
     scopes::ScopeId,
-    visitor::Visitor,
+    stars::StarExpander,
+    visitor::{SubqueryCollector, Visitor},
 };
 
 pub struct Command {
@@ -42,6 +43,26 @@ impl Command {
             }
             self.command_type.accept(visitor);
         }
+        visitor.leave_command(self);
+    }
+
+    /// Expands `*` and `table.*` in the fields of this command and all nested commands.
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        // The with items of this command are only visible within it.
+        let with_items_len = stars.with_items_len();
+        if let Some(inner) = &mut self.with {
+            inner.expand_stars(stars)?;
+        }
+        self.command_type.expand_stars(stars)?;
+        stars.truncate_with_items(with_items_len);
+        Ok(())
+    }
+
+    /// Subqueries used as expressions in this command, e.g. in `exists (...)`.
+    pub fn expr_subqueries(&self) -> Vec<&Command> {
+        let mut collector = SubqueryCollector::new(1);
+        self.accept(&mut collector);
+        collector.commands
     }
 
     pub fn fields(&self) -> Option<&Fields> {
@@ -56,6 +77,26 @@ impl Command {
     pub fn from_chain(&self) -> Option<&FromChain> {
         self.command_type.from_chain()
     }
+
+    pub fn on_conflict(&self) -> Option<&OnConflict> {
+        self.command_type.on_conflict()
+    }
+
+    pub fn r#where(&self) -> Option<&Where> {
+        self.command_type.r#where()
+    }
+
+    pub fn group_by(&self) -> Option<&GroupBy> {
+        self.command_type.group_by()
+    }
+
+    pub fn insert_select(&self) -> Option<&Command> {
+        self.command_type.insert_select()
+    }
+
+    pub fn set_operation_commands(&self) -> impl Iterator<Item = &Command> {
+        self.command_type.set_operation_commands()
+    }
 }
 
 impl Parse for Command {
@@ -102,6 +143,15 @@ impl CommandType {
         }
     }
 
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        match self {
+            Self::Delete(inner) => inner.expand_stars(stars),
+            Self::Insert(inner) => inner.expand_stars(stars),
+            Self::Select(inner) => inner.expand_stars(stars),
+            Self::Update(inner) => inner.expand_stars(stars),
+        }
+    }
+
     pub fn fields(&self) -> Option<&Fields> {
         match self {
             Self::Delete(inner) => inner.returning.as_ref().map(|returning| &returning.fields),
@@ -129,6 +179,49 @@ impl CommandType {
             Self::Update(update) => update.from.as_ref().map(|from| &from.chain),
         }
     }
+
+    pub fn on_conflict(&self) -> Option<&OnConflict> {
+        match self {
+            Self::Insert(insert) => insert.on_conflict.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn r#where(&self) -> Option<&Where> {
+        match self {
+            Self::Delete(delete) => delete.r#where.as_ref(),
+            Self::Insert(..) => None,
+            Self::Select(select) => select.r#where.as_ref(),
+            Self::Update(update) => update.r#where.as_ref(),
+        }
+    }
+
+    pub fn group_by(&self) -> Option<&GroupBy> {
+        match self {
+            Self::Select(select) => select.group_by.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn insert_select(&self) -> Option<&Command> {
+        match self {
+            Self::Insert(Insert {
+                source: InsertSource::Select(command),
+                ..
+            }) => Some(command),
+            _ => None,
+        }
+    }
+
+    pub fn set_operation_commands(&self) -> impl Iterator<Item = &Command> {
+        let set_operations = match self {
+            Self::Select(select) => select.set_operations.as_slice(),
+            _ => &[],
+        };
+        set_operations
+            .iter()
+            .map(|set_operation| set_operation.command.as_ref())
+    }
 }
 
 impl Parse for CommandType {
@@ -3,8 +3,12 @@ use quote::{ToTokens, quote};
 use syn::parse::{Parse, ParseStream};
 
 use crate::{
-    clause::{self, From, GroupBy, Having, Limit, Offset, OrderBy, Where},
+    clause::{
+        self, From, GroupBy, Having, Limit, Lock, Offset, OrderBy, SetOperation, Where, Window,
+    },
+    command::CommandType,
     quote_option::QuoteOption,
+    stars::{StarExpander, StarSource},
     visitor::Visitor,
 };
 
@@ -14,9 +18,12 @@ pub struct Select {
     pub r#where: Option<Where>,
     pub group_by: Option<GroupBy>,
     pub having: Option<Having>,
+    pub window: Option<Window>,
+    pub set_operations: Vec<SetOperation>,
     pub order_by: Option<OrderBy>,
     pub limit: Option<Limit>,
     pub offset: Option<Offset>,
+    pub lock: Option<Lock>,
 }
 
 impl Select {
@@ -38,6 +45,12 @@ impl Select {
         if let Some(inner) = self.having.as_ref() {
             inner.accept(visitor)
         }
+        if let Some(inner) = self.window.as_ref() {
+            inner.accept(visitor)
+        }
+        for inner in &self.set_operations {
+            inner.accept(visitor)
+        }
         if let Some(inner) = self.order_by.as_ref() {
             inner.accept(visitor)
         }
@@ -47,22 +60,110 @@ impl Select {
         if let Some(inner) = self.offset.as_ref() {
             inner.accept(visitor)
         }
+        if let Some(inner) = self.lock.as_ref() {
+            inner.accept(visitor)
+        }
+    }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        if let Some(inner) = &mut self.from {
+            inner.expand_stars(stars)?;
+        }
+        let sources = self
+            .from
+            .iter()
+            .flat_map(|from| &from.chain)
+            .map(|from_item| StarSource::from_item(from_item, stars))
+            .collect::<Vec<_>>();
+        self.select.expand_stars(&sources, stars)?;
+        if let Some(inner) = &mut self.r#where {
+            inner.expand_stars(stars)?;
+        }
+        if let Some(inner) = &mut self.group_by {
+            inner.expand_stars(stars)?;
+        }
+        if let Some(inner) = &mut self.having {
+            inner.expand_stars(stars)?;
+        }
+        if let Some(inner) = &mut self.window {
+            inner.expand_stars(stars)?;
+        }
+        for set_operation in &mut self.set_operations {
+            set_operation.expand_stars(stars)?;
+        }
+        self.check_set_operations()?;
+        if let Some(inner) = &mut self.order_by {
+            inner.expand_stars(stars)?;
+        }
+        if let Some(inner) = &mut self.limit {
+            inner.expand_stars(stars)?;
+        }
+        if let Some(inner) = &mut self.offset {
+            inner.expand_stars(stars)?;
+        }
+        Ok(())
     }
 }
 
-impl Parse for Select {
-    fn parse(input: ParseStream) -> syn::Result<Self> {
+impl Select {
+    /// Parses a `select` without set operations, `order by`, `limit` and `offset`, which is what
+    /// each branch of a set operation consists of.
+    pub fn parse_core(input: ParseStream) -> syn::Result<Self> {
         Ok(Self {
             select: input.parse()?,
             from: input.call(From::parse_optional)?,
             r#where: input.call(Where::parse_optional)?,
             group_by: input.call(GroupBy::parse_optional)?,
             having: input.call(Having::parse_optional)?,
-            order_by: input.call(OrderBy::parse_optional)?,
-            limit: input.call(Limit::parse_optional)?,
-            offset: input.call(Offset::parse_optional)?,
+            window: input.call(Window::parse_optional)?,
+            set_operations: Vec::new(),
+            order_by: None,
+            limit: None,
+            offset: None,
+            lock: None,
         })
     }
+
+    /// Checks that the branches of the set operations select as many fields as this select. The
+    /// field counts are only compared once `*` has been expanded.
+    fn check_set_operations(&self) -> syn::Result<()> {
+        if !self.select.fields.is_expanded() {
+            return Ok(());
+        }
+        let field_count = self.select.fields.fields.len();
+        for set_operation in &self.set_operations {
+            let CommandType::Select(branch) = &set_operation.command.command_type else {
+                unreachable!("set operation branches are always selects");
+            };
+            if !branch.select.fields.is_expanded() {
+                continue;
+            }
+            if branch.select.fields.fields.len() != field_count {
+                return Err(syn::Error::new(
+                    branch.select._select.span,
+                    format!(
+                        "each branch of a set operation must select the same number of fields, \
+                        expected {field_count} but found {}",
+                        branch.select.fields.fields.len()
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Parse for Select {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut select = Self::parse_core(input)?;
+
+        select.set_operations = input.call(SetOperation::parse_many)?;
+        select.order_by = input.call(OrderBy::parse_optional)?;
+        select.limit = input.call(Limit::parse_optional)?;
+        select.offset = input.call(Offset::parse_optional)?;
+        select.lock = input.call(Lock::parse_optional)?;
+        Ok(select)
+    }
 }
 
 impl ToTokens for Select {
@@ -72,9 +173,12 @@ impl ToTokens for Select {
         let r#where = QuoteOption::from(&self.r#where);
         let group_by = QuoteOption::from(&self.group_by);
         let having = QuoteOption::from(&self.having);
+        let window = QuoteOption::from(&self.window);
+        let set_operations = self.set_operations.iter();
         let order_by = QuoteOption::from(&self.order_by);
         let limit = QuoteOption::from(&self.limit);
         let offset = QuoteOption::from(&self.offset);
+        let lock = QuoteOption::from(&self.lock);
 
         quote! {
             ::kosame::repr::command::Select::new(
@@ -83,9 +187,12 @@ impl ToTokens for Select {
                 #r#where,
                 #group_by,
                 #having,
+                #window,
+                &[#(#set_operations),*],
                 #order_by,
                 #limit,
                 #offset,
+                #lock,
             )
         }
         .to_tokens(tokens);
@@ -2,7 +2,14 @@ use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 use syn::parse::{Parse, ParseStream};
 
-use crate::{clause::*, keyword, part::TargetTable, quote_option::QuoteOption, visitor::Visitor};
+use crate::{
+    clause::*,
+    keyword,
+    part::TargetTable,
+    quote_option::QuoteOption,
+    stars::{StarExpander, StarSource},
+    visitor::Visitor,
+};
 
 pub struct Update {
     pub _update_keyword: keyword::update,
@@ -21,6 +28,9 @@ impl Update {
     pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
         self.target_table.accept(visitor);
         self.set.accept(visitor);
+        if let Some(inner) = &self.from {
+            inner.accept(visitor)
+        }
         if let Some(inner) = &self.r#where {
             inner.accept(visitor)
         }
@@ -28,6 +38,28 @@ impl Update {
             inner.accept(visitor)
         }
     }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        self.set.expand_stars(stars)?;
+        if let Some(inner) = &mut self.from {
+            inner.expand_stars(stars)?;
+        }
+        if let Some(inner) = &mut self.r#where {
+            inner.expand_stars(stars)?;
+        }
+        if let Some(returning) = &mut self.returning {
+            let sources = std::iter::once(StarSource::target_table(&self.target_table, stars))
+                .chain(
+                    self.from
+                        .iter()
+                        .flat_map(|from| &from.chain)
+                        .map(|from_item| StarSource::from_item(from_item, stars)),
+                )
+                .collect::<Vec<_>>();
+            returning.fields.expand_stars(&sources, stars)?;
+        }
+        Ok(())
+    }
 }
 
 impl Parse for Update {
@@ -5,12 +5,13 @@ use quote::{ToTokens, format_ident, quote};
 use syn::Ident;
 
 use crate::{
-    clause::{FromItem, WithItem},
+    clause::{FromItem, OnConflict, ValuesItem, WithItem},
     command::Command,
     inferred_type::InferredType,
     part::TablePath,
     path_ext::PathExt,
     query::{self, Query, QueryNodePath},
+    scopes::ScopeId,
 };
 
 thread_local! {
@@ -92,7 +93,9 @@ enum Correlation<'a> {
     Table(&'a TablePath, Option<&'a WithItem>),
     Command(&'a Command),
     WithItem(&'a WithItem),
-    FromItem(&'a FromItem),
+    /// A from item in the command with the given scope.
+    FromItem(&'a FromItem, ScopeId),
+    Excluded(&'a OnConflict, &'a TablePath),
     QueryNodePath {
         node: &'a query::Node,
         table_path: &'a TablePath,
@@ -106,7 +109,8 @@ impl<'a> Correlation<'a> {
             Self::Table(inner, _) => inner.correlation_id,
             Self::Command(inner) => inner.correlation_id,
             Self::WithItem(inner) => inner.correlation_id,
-            Self::FromItem(inner) => inner.correlation_id(),
+            Self::FromItem(inner, _) => inner.correlation_id(),
+            Self::Excluded(inner, _) => inner.excluded_correlation_id,
             Self::QueryNodePath { node, .. } => node.correlation_id,
         }
     }
@@ -118,10 +122,12 @@ impl<'a> Correlation<'a> {
             }
             Self::Command(_) => None,
             Self::WithItem(inner) => Some(inner.command.correlation_id),
-            Self::FromItem(inner) => match inner {
+            Self::FromItem(inner, _) => match inner {
                 FromItem::Table { table_path, .. } => Some(table_path.correlation_id),
                 FromItem::Subquery { command, .. } => Some(command.correlation_id),
+                FromItem::Function { .. } | FromItem::Values { .. } => None,
             },
+            Self::Excluded(_, table_path) => Some(table_path.correlation_id),
             Self::QueryNodePath { .. } => None,
         }
     }
@@ -143,12 +149,20 @@ impl<'a> Correlation<'a> {
                     .find(|field| field.infer_name() == Some(column))?;
                 field.infer_type(command.scope_id)
             }
-            Self::WithItem(with_item) => Some(InferredType::Correlation {
-                correlation_id: with_item.command.correlation_id,
-                column,
-                nullable: false,
-            }),
-            Self::FromItem(from_item) => match from_item {
+            Self::WithItem(with_item) => match &with_item.alias.columns {
+                // Columns named in the alias correspond to the fields of the command by position.
+                Some(columns) => {
+                    let index = columns.columns.iter().position(|alias| alias == column)?;
+                    let field = with_item.command.fields()?.iter().nth(index)?;
+                    field.infer_type(with_item.command.scope_id)
+                }
+                None => Some(InferredType::Correlation {
+                    correlation_id: with_item.command.correlation_id,
+                    column,
+                    nullable: false,
+                }),
+            },
+            Self::FromItem(from_item, scope_id) => match from_item {
                 FromItem::Table { table_path, .. } => Some(InferredType::Correlation {
                     correlation_id: table_path.correlation_id,
                     column,
@@ -159,7 +173,35 @@ impl<'a> Correlation<'a> {
                     column,
                     nullable: false,
                 }),
+                // Only the type of `generate_series` is known, which is that of its arguments.
+                FromItem::Function { call, .. } => match call.function.to_string().as_str() {
+                    "generate_series" => Some(InferredType::same(call.params.first(), *scope_id)),
+                    _ => None,
+                },
+                FromItem::Values { values, alias, .. } => {
+                    let index = alias
+                        .columns
+                        .iter()
+                        .flat_map(|columns| columns.columns.iter())
+                        .position(|alias| alias == column)?;
+                    let exprs = values
+                        .rows
+                        .iter()
+                        .map(|row| match row.items.iter().nth(index) {
+                            Some(ValuesItem::Expr(expr)) => Some(expr),
+                            _ => None,
+                        });
+                    Some(InferredType::same(
+                        exprs.collect::<Option<Vec<_>>>()?,
+                        *scope_id,
+                    ))
+                }
             },
+            Self::Excluded(_, table_path) => Some(InferredType::Correlation {
+                correlation_id: table_path.correlation_id,
+                column,
+                nullable: false,
+            }),
             Self::QueryNodePath { table_path, .. } => {
                 Some(InferredType::TableColumn { table_path, column })
             }
@@ -203,9 +245,7 @@ impl ToTokens for Correlation<'_> {
                 None => quote! { pub mod #id {} },
             },
             Self::WithItem(with_item) => match &with_item.alias.columns {
-                Some(_) => {
-                    unimplemented!();
-                }
+                Some(columns) => columns_module(id, &with_item.alias.name, columns.columns.iter()),
                 None => {
                     let source_id = with_item.command.correlation_id;
                     let alias = with_item.alias.name.to_string();
@@ -217,7 +257,7 @@ impl ToTokens for Correlation<'_> {
                     }
                 }
             },
-            Self::FromItem(from_item) => match from_item {
+            Self::FromItem(from_item, _) => match from_item {
                 FromItem::Table {
                     table_path, alias, ..
                 } => {
@@ -250,7 +290,24 @@ impl ToTokens for Correlation<'_> {
                         None => quote! { pub use #source_id as #id; },
                     }
                 }
+                FromItem::Function { .. } | FromItem::Values { .. } => columns_module(
+                    id,
+                    from_item
+                        .name()
+                        .expect("functions and values lists have a name"),
+                    from_item.columns(None).into_iter(),
+                ),
             },
+            Self::Excluded(on_conflict, table_path) => {
+                let source_id = table_path.correlation_id;
+                let name = on_conflict.excluded.to_string();
+                quote! {
+                    pub mod #id {
+                        pub const TABLE_NAME: &str = #name;
+                        pub use super::#source_id::columns;
+                    }
+                }
+            }
             Self::QueryNodePath {
                 table_path,
                 node_path,
@@ -266,61 +323,105 @@ impl ToTokens for Correlation<'_> {
     }
 }
 
-impl<'a> From<&'a Command> for Correlations<'a> {
-    fn from(value: &'a Command) -> Self {
-        fn inner<'a>(
-            correlations: &mut Vec<Correlation<'a>>,
-            command: &'a Command,
-            inherited_with_items: &mut Vec<&'a WithItem>,
-        ) {
-            let with_items_truncate = inherited_with_items.len();
+/// A correlation whose columns are named explicitly, e.g. by the column list of an alias.
+fn columns_module<'a>(
+    id: &CorrelationId,
+    table_name: &Ident,
+    columns: impl Iterator<Item = &'a Ident> + Clone,
+) -> TokenStream {
+    let table_name = table_name.to_string();
+    let column_strings = columns.clone().map(|column| column.to_string());
+    quote! {
+        pub mod #id {
+            pub const TABLE_NAME: &str = #table_name;
+            pub mod columns {
+                #(
+                    pub mod #columns {
+                        pub const COLUMN_NAME: &str = #column_strings;
+                    }
+                )*
+            }
+        }
+    }
+}
 
-            correlations.push(Correlation::Command(command));
+fn command_correlations<'a>(
+    correlations: &mut Vec<Correlation<'a>>,
+    command: &'a Command,
+    inherited_with_items: &mut Vec<&'a WithItem>,
+) {
+    let with_items_truncate = inherited_with_items.len();
 
-            if let Some(with) = &command.with {
-                for with_item in &with.items {
-                    correlations.push(Correlation::WithItem(with_item));
-                    inner(correlations, &with_item.command, inherited_with_items);
-                    inherited_with_items.push(with_item);
-                }
-            }
+    correlations.push(Correlation::Command(command));
 
-            if let Some(target_table) = command.target_table() {
-                correlations.push(Correlation::Table(&target_table.table, None));
+    if let Some(with) = &command.with {
+        for with_item in &with.items {
+            correlations.push(Correlation::WithItem(with_item));
+            // The items of a recursive with clause may refer to themselves.
+            if with.recursive.is_some() {
+                inherited_with_items.push(with_item);
+                command_correlations(correlations, &with_item.command, inherited_with_items);
+            } else {
+                command_correlations(correlations, &with_item.command, inherited_with_items);
+                inherited_with_items.push(with_item);
             }
+        }
+    }
 
-            if let Some(from_chain) = command.from_chain() {
-                for from_item in from_chain {
-                    correlations.push(Correlation::FromItem(from_item));
-
-                    match from_item {
-                        FromItem::Table { table_path, .. } => {
-                            let with_item = match from_item {
-                                FromItem::Table { table_path, .. } => {
-                                    match table_path.get_ident() {
-                                        Some(table) => inherited_with_items
-                                            .iter()
-                                            .rev()
-                                            .find(|with_item| with_item.alias.name == *table),
-                                        None => None,
-                                    }
-                                }
-                                _ => None,
-                            };
-                            correlations.push(Correlation::Table(table_path, with_item.copied()));
-                        }
-                        FromItem::Subquery { command, .. } => {
-                            inner(correlations, command, inherited_with_items);
-                        }
-                    }
+    if let Some(target_table) = command.target_table() {
+        correlations.push(Correlation::Table(&target_table.table, None));
+
+        if let Some(on_conflict) = command.on_conflict() {
+            correlations.push(Correlation::Excluded(on_conflict, &target_table.table));
+        }
+    }
+
+    if let Some(command) = command.insert_select() {
+        command_correlations(correlations, command, inherited_with_items);
+    }
+
+    if let Some(from_chain) = command.from_chain() {
+        for from_item in from_chain {
+            correlations.push(Correlation::FromItem(from_item, command.scope_id));
+
+            match from_item {
+                FromItem::Table { table_path, .. } => {
+                    let with_item = match from_item {
+                        FromItem::Table { table_path, .. } => match table_path.get_ident() {
+                            Some(table) => inherited_with_items
+                                .iter()
+                                .rev()
+                                .find(|with_item| with_item.alias.name == *table),
+                            None => None,
+                        },
+                        _ => None,
+                    };
+                    correlations.push(Correlation::Table(table_path, with_item.copied()));
+                }
+                FromItem::Subquery { command, .. } => {
+                    command_correlations(correlations, command, inherited_with_items);
                 }
+                FromItem::Function { .. } | FromItem::Values { .. } => {}
             }
-
-            inherited_with_items.truncate(with_items_truncate);
         }
+    }
+
+    // Subqueries in expressions may refer to the with items of this command.
+    for command in command.expr_subqueries() {
+        command_correlations(correlations, command, inherited_with_items);
+    }
+
+    for command in command.set_operation_commands() {
+        command_correlations(correlations, command, inherited_with_items);
+    }
+
+    inherited_with_items.truncate(with_items_truncate);
+}
 
+impl<'a> From<&'a Command> for Correlations<'a> {
+    fn from(value: &'a Command) -> Self {
         let mut correlations = Vec::new();
-        inner(&mut correlations, value, &mut Vec::new());
+        command_correlations(&mut correlations, value, &mut Vec::new());
         Correlations { correlations }
     }
 }
@@ -333,6 +434,10 @@ impl<'a> From<&'a Query> for Correlations<'a> {
             node: &'a query::Node,
             node_path: QueryNodePath,
         ) {
+            for command in node.expr_subqueries() {
+                command_correlations(correlations, command, &mut Vec::new());
+            }
+
             for field in node.fields.iter() {
                 if let query::Field::Relation { node, name, .. } = field {
                     inner(
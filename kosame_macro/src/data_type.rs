@@ -1,4 +1,5 @@
 use proc_macro_error::abort;
+use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 use syn::{
     Ident,
@@ -18,9 +19,10 @@ impl Parse for DataType {
     }
 }
 
-impl ToTokens for DataType {
-    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        match self.name.to_string().as_str() {
+impl DataType {
+    /// The Rust type of non-null values of this database type, if it is known.
+    pub fn rust_type(&self) -> Option<TokenStream> {
+        Some(match self.name.to_string().as_str() {
             // Built-in / Standard library types
             "bool" => quote! { bool },
             "char" => quote! { i8 },
@@ -32,21 +34,31 @@ impl ToTokens for DataType {
             "double precision" => quote! { f64 },
             "varchar" | "text" | "citext" | "name" | "unknown" => quote! { ::std::string::String },
             "bytea" => quote! { ::std::vec::Vec<u8> },
-            "hstore" => quote! { ::std::collections::HashMap<::std::string::String, ::std::option::Option<::std::string::String>> },
-            "timestamp" | "timestamptz" | "timestamp with time zone" => quote! { ::std::time::SystemTime },
+            "hstore" => {
+                quote! { ::std::collections::HashMap<::std::string::String, ::std::option::Option<::std::string::String>> }
+            }
+            "timestamp" | "timestamptz" | "timestamp with time zone" => {
+                quote! { ::std::time::SystemTime }
+            }
             "inet" => quote! { ::std::net::IpAddr },
 
             // Crates
             "uuid" => quote! { ::uuid::Uuid },
             "json" | "jsonb" => quote! { ::serde_json::Value },
-            _ => {
-                abort!(
-                    self.name.span(),
-                    "cannot determine rust type for unrecognized database type {}, requires type override",
-                    self.name,
-                );
-            }
-        }
-        .to_tokens(tokens);
+            _ => return None,
+        })
+    }
+}
+
+impl ToTokens for DataType {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Some(rust_type) = self.rust_type() else {
+            abort!(
+                self.name.span(),
+                "cannot determine rust type for unrecognized database type {}, requires type override",
+                self.name,
+            );
+        };
+        rust_type.to_tokens(tokens);
     }
 }
@@ -0,0 +1,162 @@
+use proc_macro_error::emit_error;
+
+use syn::Ident;
+
+use crate::{
+    clause::{ConflictAction, Distinct, Lock, LockStrength},
+    command::{Command, CommandType, Insert, InsertSource},
+    driver::Driver,
+    expr::{Any, BinOp, Binary, Expr},
+    visitor::Visitor,
+};
+
+/// Reports syntax that the dialect of the driver cannot express. The SQL is only rendered at
+/// runtime, so without these checks such statements would compile but always fail.
+pub struct DialectCheck {
+    driver: Driver,
+}
+
+impl DialectCheck {
+    pub fn new(driver: Driver) -> Self {
+        Self { driver }
+    }
+}
+
+impl<'a> Visitor<'a> for DialectCheck {
+    fn visit_any(&mut self, any: &'a Any) {
+        // Without array support, `= any(...)` is expanded into an `in` list, which requires the
+        // number of elements to be known when the SQL is rendered.
+        if !self.driver.supports_arrays() && !matches!(*any.rhs, Expr::BindParam(_)) {
+            emit_error!(
+                any.rhs.span(),
+                "`= any(...)` only accepts a bind parameter with the `{}` driver",
+                self.driver.as_str()
+            );
+        }
+    }
+
+    fn visit_binary(&mut self, binary: &'a Binary) {
+        if let BinOp::ILike(ilike) | BinOp::NotILike(_, ilike) = &binary.op
+            && !self.driver.supports_ilike()
+        {
+            emit_error!(
+                ilike.span,
+                "`ilike` is not supported with the `{}` driver",
+                self.driver.as_str();
+                help = "compare in lower case instead, e.g. `lower(..) like ..`"
+            );
+        }
+    }
+
+    fn visit_command(&mut self, command: &'a Command) {
+        let returning = match &command.command_type {
+            CommandType::Delete(inner) => &inner.returning,
+            CommandType::Insert(inner) => &inner.returning,
+            CommandType::Update(inner) => &inner.returning,
+            CommandType::Select(_) => return,
+        };
+        if let Some(returning) = returning
+            && !self.driver.supports_returning()
+        {
+            emit_error!(
+                returning._returning.span,
+                "`returning` is not supported with the `{}` driver",
+                self.driver.as_str()
+            );
+        }
+    }
+
+    fn visit_relation(&mut self, name: &'a Ident) {
+        if !self.driver.supports_relations() {
+            emit_error!(
+                name,
+                "relation fields are not supported with the `{}` driver",
+                self.driver.as_str();
+                help = "relational queries are only available with the `postgres` and `tokio-postgres` drivers"
+            );
+        }
+    }
+
+    fn visit_distinct(&mut self, distinct: &'a Distinct) {
+        if let Some(on) = &distinct.on
+            && !self.driver.supports_distinct_on()
+        {
+            emit_error!(
+                on._on.span,
+                "`distinct on` is not supported with the `{}` driver",
+                self.driver.as_str()
+            );
+        }
+    }
+
+    fn visit_insert(&mut self, insert: &'a Insert) {
+        let Some(on_conflict) = &insert.on_conflict else {
+            return;
+        };
+        if let Some(target) = &on_conflict.target
+            && !self.driver.supports_conflict_target()
+        {
+            emit_error!(
+                target._paren_token.span.join(),
+                "`on conflict` cannot have a conflict target with the `{}` driver",
+                self.driver.as_str();
+                note = "`on duplicate key update` handles conflicts on any unique key"
+            );
+        }
+        if let ConflictAction::Nothing { _nothing_keyword } = &on_conflict.action
+            && !self.driver.supports_conflict_do_nothing()
+        {
+            emit_error!(
+                _nothing_keyword,
+                "`on conflict do nothing` is not supported with the `{}` driver",
+                self.driver.as_str();
+                note = "`insert ignore` would also ignore errors other than conflicts"
+            );
+        }
+        let ConflictAction::Update {
+            _update_keyword,
+            r#where,
+            ..
+        } = &on_conflict.action
+        else {
+            return;
+        };
+        if let InsertSource::Select(_) = insert.source
+            && !self.driver.supports_upsert_from_select()
+        {
+            emit_error!(
+                _update_keyword,
+                "`insert ... select` cannot be combined with `on conflict do update` with the `{}` driver",
+                self.driver.as_str()
+            );
+        }
+        if let Some(r#where) = r#where
+            && !self.driver.supports_filtered_upsert()
+        {
+            emit_error!(
+                r#where._where,
+                "`on conflict do update` cannot have a `where` clause with the `{}` driver",
+                self.driver.as_str()
+            );
+        }
+    }
+
+    fn visit_lock(&mut self, lock: &'a Lock) {
+        if !self.driver.supports_row_locks() {
+            emit_error!(
+                lock._for.span,
+                "rows cannot be locked with the `{}` driver",
+                self.driver.as_str()
+            );
+        } else if let LockStrength::NoKeyUpdate(..) | LockStrength::KeyShare(..) = lock.strength
+            && !self.driver.supports_key_locks()
+        {
+            emit_error!(
+                lock.strength.span(),
+                "`for no key update` and `for key share` are not supported with the `{}` driver",
+                self.driver.as_str();
+                help = "use `for update` or `for share` instead"
+            );
+        }
+    }
+}
@@ -1,5 +1,9 @@
 use std::str::FromStr;
 
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Driver {
     Postgres,
     TokioPostgres,
@@ -7,6 +11,122 @@ pub enum Driver {
     Rusqlite,
 }
 
+impl Driver {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Postgres => "postgres",
+            Self::TokioPostgres => "tokio-postgres",
+            Self::Mysql => "mysql",
+            Self::Rusqlite => "rusqlite",
+        }
+    }
+
+    /// Whether the driver binds arrays as a single parameter. Otherwise, array bind parameters are
+    /// expanded into one bind parameter per element at runtime.
+    pub fn supports_arrays(&self) -> bool {
+        match self {
+            Self::Postgres | Self::TokioPostgres => true,
+            Self::Mysql | Self::Rusqlite => false,
+        }
+    }
+
+    /// Whether inserts, updates and deletes can return rows with `returning`.
+    pub fn supports_returning(&self) -> bool {
+        match self {
+            Self::Postgres | Self::TokioPostgres | Self::Rusqlite => true,
+            Self::Mysql => false,
+        }
+    }
+
+    /// Whether queries can have relation fields, which are fetched as arrays of records with
+    /// PostgreSQL's `array(select row(...))`.
+    pub fn supports_relations(&self) -> bool {
+        match self {
+            Self::Postgres | Self::TokioPostgres => true,
+            Self::Mysql | Self::Rusqlite => false,
+        }
+    }
+
+    /// Whether `on conflict` can name the columns that conflict. MySQL's `on duplicate key update`
+    /// handles conflicts on any unique key.
+    pub fn supports_conflict_target(&self) -> bool {
+        match self {
+            Self::Postgres | Self::TokioPostgres | Self::Rusqlite => true,
+            Self::Mysql => false,
+        }
+    }
+
+    /// Whether `on conflict do nothing` is supported. MySQL can only skip conflicting rows with
+    /// `insert ignore`, which also turns other errors into warnings.
+    pub fn supports_conflict_do_nothing(&self) -> bool {
+        match self {
+            Self::Postgres | Self::TokioPostgres | Self::Rusqlite => true,
+            Self::Mysql => false,
+        }
+    }
+
+    /// Whether `on conflict do update` can be filtered by a `where` clause. MySQL's
+    /// `on duplicate key update` always updates the conflicting row.
+    pub fn supports_filtered_upsert(&self) -> bool {
+        match self {
+            Self::Postgres | Self::TokioPostgres | Self::Rusqlite => true,
+            Self::Mysql => false,
+        }
+    }
+
+    /// Whether `insert ... select` can be combined with `on conflict do update`. MySQL can only
+    /// refer to the inserted row, which stands in for `excluded`, when inserting a `values` list.
+    pub fn supports_upsert_from_select(&self) -> bool {
+        match self {
+            Self::Postgres | Self::TokioPostgres | Self::Rusqlite => true,
+            Self::Mysql => false,
+        }
+    }
+
+    /// Whether case-insensitive pattern matching with `ilike` is supported, which is an
+    /// extension of PostgreSQL.
+    pub fn supports_ilike(&self) -> bool {
+        match self {
+            Self::Postgres | Self::TokioPostgres => true,
+            Self::Mysql | Self::Rusqlite => false,
+        }
+    }
+
+    /// Whether `select distinct on (...)` is supported, which is an extension of PostgreSQL.
+    pub fn supports_distinct_on(&self) -> bool {
+        match self {
+            Self::Postgres | Self::TokioPostgres => true,
+            Self::Mysql | Self::Rusqlite => false,
+        }
+    }
+
+    /// Whether rows can be locked with `for update`, `for share` and their variants. SQLite locks
+    /// the whole database instead.
+    pub fn supports_row_locks(&self) -> bool {
+        match self {
+            Self::Postgres | Self::TokioPostgres | Self::Mysql => true,
+            Self::Rusqlite => false,
+        }
+    }
+
+    /// Whether rows can be locked with `for no key update` and `for key share`, which only
+    /// PostgreSQL distinguishes from `for update` and `for share`.
+    pub fn supports_key_locks(&self) -> bool {
+        match self {
+            Self::Postgres | Self::TokioPostgres => true,
+            Self::Mysql | Self::Rusqlite => false,
+        }
+    }
+
+    pub fn dialect(&self) -> TokenStream {
+        match self {
+            Self::Postgres | Self::TokioPostgres => quote! { ::kosame::sql::postgres::Dialect },
+            Self::Mysql => quote! { ::kosame::sql::mysql::Dialect },
+            Self::Rusqlite => quote! { ::kosame::sql::sqlite::Dialect },
+        }
+    }
+}
+
 impl FromStr for Driver {
     type Err = ();
 
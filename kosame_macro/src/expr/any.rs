@@ -0,0 +1,82 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{Ident, Token, parenthesized, parse::ParseStream, parse_quote, spanned::Spanned};
+
+use crate::{inferred_type::InferredType, keyword, scopes::ScopeId, stars::StarExpander};
+
+use super::{Expr, Visitor};
+
+/// `lhs = any(rhs)`, where `rhs` is an array. If `rhs` is a bind parameter, it is bound as an
+/// array and expanded into an `in` list for dialects without array support.
+pub struct Any {
+    pub lhs: Box<Expr>,
+    pub _eq: Token![=],
+    pub _any: keyword::any,
+    pub paren: syn::token::Paren,
+    pub rhs: Box<Expr>,
+}
+
+impl Any {
+    pub const PRECEDENCE: u32 = 5;
+
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(Token![=]) && input.peek2(keyword::any) && input.peek3(syn::token::Paren)
+    }
+
+    /// Parses the remainder of an `= any(...)` expression whose left hand side has already been
+    /// parsed.
+    pub fn parse_rest(lhs: Expr, input: ParseStream) -> syn::Result<Self> {
+        let content;
+        Ok(Self {
+            lhs: Box::new(lhs),
+            _eq: input.parse()?,
+            _any: input.parse()?,
+            paren: parenthesized!(content in input),
+            rhs: content.parse()?,
+        })
+    }
+
+    pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        visitor.visit_any(self);
+        self.lhs.accept(visitor);
+        match self.rhs.as_ref() {
+            Expr::BindParam(bind_param) => visitor.visit_array_bind_param(bind_param),
+            rhs => rhs.accept(visitor),
+        }
+    }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        self.lhs.expand_stars(stars)?;
+        self.rhs.expand_stars(stars)
+    }
+
+    pub fn infer_name(&self) -> Option<&Ident> {
+        None
+    }
+
+    pub fn infer_type(&self, scope_id: ScopeId) -> Option<InferredType<'_>> {
+        Some(InferredType::derived(
+            parse_quote!(bool),
+            [self.lhs.as_ref(), self.rhs.as_ref()],
+            scope_id,
+        ))
+    }
+
+    pub fn span(&self) -> Span {
+        self.lhs
+            .span()
+            .join(self.paren.span.span())
+            .unwrap_or(self.lhs.span())
+    }
+}
+
+impl ToTokens for Any {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let lhs = &self.lhs;
+        let rhs = &self.rhs;
+        quote! {
+            ::kosame::repr::expr::Any::new(&#lhs, &#rhs)
+        }
+        .to_tokens(tokens);
+    }
+}
@@ -0,0 +1,83 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{Ident, parse::ParseStream, parse_quote};
+
+use crate::{inferred_type::InferredType, keyword, scopes::ScopeId, stars::StarExpander};
+
+use super::{Expr, Visitor};
+
+pub struct Between {
+    pub lhs: Box<Expr>,
+    pub _not: Option<keyword::not>,
+    pub _between: keyword::between,
+    pub low: Box<Expr>,
+    pub _and: keyword::and,
+    pub high: Box<Expr>,
+}
+
+impl Between {
+    pub const PRECEDENCE: u32 = 6;
+
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::between) || (input.peek(keyword::not) && input.peek2(keyword::between))
+    }
+
+    /// Parses the remainder of a `between` expression whose left hand side has already been
+    /// parsed.
+    pub fn parse_rest(lhs: Expr, input: ParseStream) -> syn::Result<Self> {
+        // The bounds bind tighter than `between` itself, so that the `and` separating them is not
+        // parsed as a logical operator.
+        Ok(Self {
+            lhs: Box::new(lhs),
+            _not: input.parse()?,
+            _between: input.parse()?,
+            low: Box::new(Expr::parse_expr(input, Self::PRECEDENCE + 1)?),
+            _and: input.parse()?,
+            high: Box::new(Expr::parse_expr(input, Self::PRECEDENCE + 1)?),
+        })
+    }
+
+    pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        self.lhs.accept(visitor);
+        self.low.accept(visitor);
+        self.high.accept(visitor);
+    }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        self.lhs.expand_stars(stars)?;
+        self.low.expand_stars(stars)?;
+        self.high.expand_stars(stars)
+    }
+
+    pub fn infer_name(&self) -> Option<&Ident> {
+        None
+    }
+
+    pub fn infer_type(&self, scope_id: ScopeId) -> Option<InferredType<'_>> {
+        Some(InferredType::derived(
+            parse_quote!(bool),
+            [self.lhs.as_ref(), self.low.as_ref(), self.high.as_ref()],
+            scope_id,
+        ))
+    }
+
+    pub fn span(&self) -> Span {
+        self.lhs
+            .span()
+            .join(self.high.span())
+            .unwrap_or(self.lhs.span())
+    }
+}
+
+impl ToTokens for Between {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let lhs = &self.lhs;
+        let not = self._not.is_some();
+        let low = &self.low;
+        let high = &self.high;
+        quote! {
+            ::kosame::repr::expr::Between::new(&#lhs, #not, &#low, &#high)
+        }
+        .to_tokens(tokens);
+    }
+}
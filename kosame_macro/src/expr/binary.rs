@@ -1,11 +1,12 @@
-use crate::{inferred_type::InferredType, keyword, scopes::ScopeId};
+use crate::{inferred_type::InferredType, keyword, scopes::ScopeId, stars::StarExpander};
 
-use super::{Expr, Visitor};
+use super::{Expr, Lit, Visitor};
 use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
 use syn::{
     Ident, Token,
     parse::{Parse, ParseStream},
+    parse_quote,
 };
 
 pub struct Binary {
@@ -24,16 +25,69 @@ impl Binary {
     }
 
     pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        visitor.visit_binary(self);
         self.lhs.accept(visitor);
         self.rhs.accept(visitor);
     }
 
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        self.lhs.expand_stars(stars)?;
+        self.rhs.expand_stars(stars)
+    }
+
     pub fn infer_name(&self) -> Option<&Ident> {
         None
     }
 
-    pub fn infer_type(&self, _scope_id: ScopeId) -> Option<InferredType<'_>> {
-        None
+    pub fn infer_type(&self, scope_id: ScopeId) -> Option<InferredType<'_>> {
+        let operands = [self.lhs.as_ref(), self.rhs.as_ref()];
+        match self.op {
+            BinOp::Multiply(_)
+            | BinOp::Divide(_)
+            | BinOp::Modulo(_)
+            | BinOp::Add(_)
+            | BinOp::Subtract(_) => Some(InferredType::Same {
+                operands: vec![
+                    self.lhs.infer_type(scope_id)?,
+                    self.rhs.infer_type(scope_id)?,
+                ],
+                nullable: false,
+            }),
+            // `||` also concatenates arrays, `jsonb` and `bytea` on PostgreSQL, so the result is
+            // only known to be text if a text literal is involved. Otherwise, it has the type of
+            // its operands, e.g. `String` for two text columns.
+            BinOp::Concat(_)
+                if operands
+                    .iter()
+                    .any(|operand| matches!(operand, Expr::Lit(Lit::Str(_)))) =>
+            {
+                Some(InferredType::derived(
+                    parse_quote!(::std::string::String),
+                    operands,
+                    scope_id,
+                ))
+            }
+            BinOp::Concat(_) => Some(InferredType::same(operands, scope_id)),
+            BinOp::Is(_) | BinOp::IsNot(..) | BinOp::IsDistinctFrom(..) => {
+                Some(InferredType::NotNull(parse_quote!(bool)))
+            }
+            BinOp::Like(_)
+            | BinOp::NotLike(..)
+            | BinOp::ILike(_)
+            | BinOp::NotILike(..)
+            | BinOp::Eq(_)
+            | BinOp::Uneq(..)
+            | BinOp::LessThan(_)
+            | BinOp::GreaterThan(_)
+            | BinOp::LessThanOrEq(..)
+            | BinOp::GreaterThanOrEq(..)
+            | BinOp::And(_)
+            | BinOp::Or(_) => Some(InferredType::derived(
+                parse_quote!(bool),
+                operands,
+                scope_id,
+            )),
+        }
     }
 
     pub fn span(&self) -> Span {
@@ -70,6 +124,13 @@ pub enum BinOp {
     // addition, subtraction
     Add(Token![+]),
     Subtract(Token![-]),
+    // string concatenation
+    Concat(Token![||]),
+    // pattern matching
+    Like(keyword::like),
+    NotLike(keyword::not, keyword::like),
+    ILike(keyword::ilike),
+    NotILike(keyword::not, keyword::ilike),
     // comparison operators
     Eq(Token![=]),
     Uneq(Token![<], Token![>]),
@@ -103,6 +164,11 @@ impl BinOp {
             Self::Modulo(_) => 9,
             Self::Add(_) => 8,
             Self::Subtract(_) => 8,
+            Self::Concat(_) => 7,
+            Self::Like(_) => 6,
+            Self::NotLike(..) => 6,
+            Self::ILike(_) => 6,
+            Self::NotILike(..) => 6,
             Self::Eq(_) => 5,
             Self::Uneq(..) => 5,
             Self::LessThan(_) => 5,
@@ -131,12 +197,27 @@ impl Parse for BinOp {
             return Ok(Self::Divide(input.parse()?));
         } else if lookahead.peek(Token![%]) {
             return Ok(Self::Modulo(input.parse()?));
+        } else if lookahead.peek(Token![||]) {
+            return Ok(Self::Concat(input.parse()?));
+        } else if lookahead.peek(keyword::like) {
+            return Ok(Self::Like(input.parse()?));
+        } else if lookahead.peek(keyword::ilike) {
+            return Ok(Self::ILike(input.parse()?));
         } else if lookahead.peek(keyword::and) {
             return Ok(Self::And(input.parse()?));
         } else if lookahead.peek(keyword::or) {
             return Ok(Self::Or(input.parse()?));
         }
 
+        if lookahead.peek(keyword::not) {
+            if input.peek2(keyword::like) {
+                return Ok(Self::NotLike(input.parse()?, input.parse()?));
+            }
+            if input.peek2(keyword::ilike) {
+                return Ok(Self::NotILike(input.parse()?, input.parse()?));
+            }
+        }
+
         if lookahead.peek(keyword::is) {
             if input.peek2(keyword::not) {
                 return Ok(Self::IsNot(input.parse()?, input.parse()?));
@@ -189,6 +270,11 @@ impl ToTokens for BinOp {
             Modulo
             Add
             Subtract
+            Concat
+            Like
+            NotLike
+            ILike
+            NotILike
             Eq
             Uneq
             LessThan
@@ -1,4 +1,4 @@
-use crate::{inferred_type::InferredType, scopes::ScopeId};
+use crate::{inferred_type::InferredType, scopes::ScopeId, stars::StarExpander};
 
 use super::Visitor;
 use proc_macro2::{Span, TokenStream};
@@ -18,6 +18,10 @@ impl BindParam {
         visitor.visit_bind_param(self);
     }
 
+    pub fn expand_stars(&mut self, _stars: &mut StarExpander) -> syn::Result<()> {
+        Ok(())
+    }
+
     pub fn infer_name(&self) -> Option<&Ident> {
         Some(&self.name)
     }
@@ -1,11 +1,15 @@
-use crate::{inferred_type::InferredType, scopes::ScopeId};
+use crate::{
+    clause::WindowDefinition, inferred_type::InferredType, keyword, quote_option::QuoteOption,
+    scopes::ScopeId, stars::StarExpander,
+};
 
-use super::{Expr, Visitor};
+use super::{Expr, Lit, Visitor};
 use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
 use syn::{
     Ident, Token, parenthesized,
     parse::{Parse, ParseStream},
+    parse_quote,
     punctuated::Punctuated,
     spanned::Spanned,
 };
@@ -13,7 +17,11 @@ use syn::{
 pub struct Call {
     pub function: Ident,
     pub paren: syn::token::Paren,
+    /// The `*` of `count(*)`, in place of any parameters.
+    pub star: Option<Token![*]>,
     pub params: Punctuated<Expr, Token![,]>,
+    pub filter: Option<Box<Filter>>,
+    pub over: Option<Box<Over>>,
 }
 
 impl Call {
@@ -25,14 +33,82 @@ impl Call {
         Some(&self.function)
     }
 
-    pub fn infer_type(&self, _scope_id: ScopeId) -> Option<InferredType<'_>> {
-        None
+    /// Whether this is a call of an aggregate function that is not computed over a window.
+    pub fn is_aggregate(&self) -> bool {
+        self.over.is_none()
+            && matches!(
+                self.function.to_string().as_str(),
+                "count" | "sum" | "avg" | "min" | "max"
+            )
+    }
+
+    pub fn infer_type(&self, scope_id: ScopeId) -> Option<InferredType<'_>> {
+        let single_param = || match self.params.len() {
+            1 => self.params.first()?.infer_type(scope_id),
+            _ => None,
+        };
+        // Aggregates are `null` over zero rows. A `filter` may exclude all rows, whereas a window
+        // always contains the current row.
+        let aggregate = |inner| match (&self.filter, &self.over) {
+            (Some(_), _) => InferredType::Same {
+                operands: vec![inner],
+                nullable: true,
+            },
+            (None, Some(_)) => inner,
+            (None, None) => InferredType::Aggregate {
+                inner: Box::new(inner),
+                scope_id,
+            },
+        };
+        match self.function.to_string().as_str() {
+            "count" | "row_number" | "rank" | "dense_rank" => {
+                Some(InferredType::NotNull(parse_quote!(i64)))
+            }
+            "ntile" => Some(InferredType::NotNull(parse_quote!(i32))),
+            "percent_rank" | "cume_dist" => Some(InferredType::NotNull(parse_quote!(f64))),
+            "sum" => Some(aggregate(InferredType::Sum(Box::new(single_param()?)))),
+            "min" | "max" => Some(aggregate(single_param()?)),
+            "first_value" | "last_value" => single_param(),
+            // The offset row or the nth row may not exist.
+            "lag" | "lead" | "nth_value" => Some(InferredType::Same {
+                operands: vec![self.params.first()?.infer_type(scope_id)?],
+                nullable: true,
+            }),
+            "coalesce" => Some(InferredType::Coalesce {
+                operands: self
+                    .params
+                    .iter()
+                    .filter(|param| !matches!(param, Expr::Lit(Lit::Null(_))))
+                    .map(|param| param.infer_type(scope_id))
+                    .collect::<Option<_>>()?,
+            }),
+            _ => None,
+        }
     }
 
     pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
         for param in self.params.iter() {
             param.accept(visitor);
         }
+        if let Some(filter) = &self.filter {
+            filter.expr.accept(visitor);
+        }
+        if let Some(Over::Definition(_, definition)) = self.over.as_deref() {
+            definition.accept(visitor);
+        }
+    }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        for param in self.params.iter_mut() {
+            param.expand_stars(stars)?;
+        }
+        if let Some(filter) = &mut self.filter {
+            filter.expr.expand_stars(stars)?;
+        }
+        if let Some(Over::Definition(_, definition)) = self.over.as_deref_mut() {
+            definition.expand_stars(stars)?;
+        }
+        Ok(())
     }
 
     pub fn span(&self) -> Span {
@@ -46,10 +122,16 @@ impl Call {
 impl Parse for Call {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let content;
+        let function = input.parse()?;
+        let paren = parenthesized!(content in input);
+        let star = content.parse()?;
         Ok(Self {
-            function: input.parse()?,
-            paren: parenthesized!(content in input),
+            function,
+            paren,
+            star,
             params: content.parse_terminated(Expr::parse, Token![,])?,
+            filter: input.call(Filter::parse_optional)?.map(Box::new),
+            over: input.call(Over::parse_optional)?.map(Box::new),
         })
     }
 }
@@ -57,7 +139,12 @@ impl Parse for Call {
 impl ToTokens for Call {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let function_name = &self.function.to_string();
-        let params = self.params.iter();
+        let params = match self.star {
+            Some(_) => vec![quote! {
+                ::kosame::repr::expr::Expr::Raw(::kosame::repr::expr::Raw::new("*"))
+            }],
+            None => self.params.iter().map(ToTokens::to_token_stream).collect(),
+        };
 
         // Some functions like `coalesce` must not be quoted like an identifier, whereas others,
         // like `sum`, can be. User defined functions should be treated as identifiers.
@@ -66,13 +153,91 @@ impl ToTokens for Call {
             "coalesce" | "greatest" | "least" | "nullif"
         );
 
+        let aggregate = (self.filter.is_some() || self.over.is_some()).then(|| {
+            let filter = QuoteOption(self.filter.as_ref().map(|filter| {
+                let expr = &filter.expr;
+                quote! { &#expr }
+            }));
+            let over = QuoteOption(self.over.as_deref().map(|over| match over {
+                Over::Window(_, name) => {
+                    let name = name.to_string();
+                    quote! { ::kosame::repr::expr::Over::Window(#name) }
+                }
+                Over::Definition(_, definition) => {
+                    quote! { ::kosame::repr::expr::Over::Definition(#definition) }
+                }
+            }));
+            quote! { &::kosame::repr::expr::Aggregate::new(#filter, #over) }
+        });
+        let aggregate = QuoteOption(aggregate);
+
         quote! {
             ::kosame::repr::expr::Call::new(
                 #function_name,
                 &[#(&#params),*],
                 #keyword,
+                #aggregate,
             )
         }
         .to_tokens(tokens)
     }
 }
+
+/// The `filter (where ...)` of an aggregate function call.
+pub struct Filter {
+    pub _filter: keyword::filter,
+    pub _paren: syn::token::Paren,
+    pub _where: Token![where],
+    pub expr: Expr,
+}
+
+impl Filter {
+    pub fn parse_optional(input: ParseStream) -> syn::Result<Option<Self>> {
+        Self::peek(input).then(|| input.parse()).transpose()
+    }
+
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::filter) && input.peek2(syn::token::Paren)
+    }
+}
+
+impl Parse for Filter {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        Ok(Self {
+            _filter: input.parse()?,
+            _paren: parenthesized!(content in input),
+            _where: content.parse()?,
+            expr: content.parse()?,
+        })
+    }
+}
+
+/// The `over ...` of a window function call, either naming a window from the `window` clause or
+/// defining one inline.
+#[allow(unused)]
+pub enum Over {
+    Window(keyword::over, Ident),
+    Definition(keyword::over, Box<WindowDefinition>),
+}
+
+impl Over {
+    pub fn parse_optional(input: ParseStream) -> syn::Result<Option<Self>> {
+        Self::peek(input).then(|| input.parse()).transpose()
+    }
+
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::over)
+    }
+}
+
+impl Parse for Over {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let over = input.parse()?;
+        if input.peek(syn::token::Paren) {
+            Ok(Self::Definition(over, input.parse()?))
+        } else {
+            Ok(Self::Window(over, input.parse()?))
+        }
+    }
+}
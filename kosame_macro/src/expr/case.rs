@@ -0,0 +1,182 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{
+    Ident, Token,
+    parse::{Parse, ParseStream},
+};
+
+use crate::{
+    inferred_type::InferredType, keyword, quote_option::QuoteOption, scopes::ScopeId,
+    stars::StarExpander,
+};
+
+use super::{Expr, Lit, Visitor};
+
+pub struct Case {
+    pub _case: keyword::case,
+    /// The operand of a simple `case` expression, which is compared to each `when` value.
+    pub operand: Option<Box<Expr>>,
+    pub branches: Vec<CaseBranch>,
+    pub r#else: Option<CaseElse>,
+    pub _end: keyword::end,
+}
+
+impl Case {
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::case)
+    }
+
+    pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        if let Some(operand) = &self.operand {
+            operand.accept(visitor);
+        }
+        for branch in &self.branches {
+            branch.condition.accept(visitor);
+            branch.result.accept(visitor);
+        }
+        if let Some(r#else) = &self.r#else {
+            r#else.result.accept(visitor);
+        }
+    }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        if let Some(operand) = &mut self.operand {
+            operand.expand_stars(stars)?;
+        }
+        for branch in &mut self.branches {
+            branch.condition.expand_stars(stars)?;
+            branch.result.expand_stars(stars)?;
+        }
+        if let Some(r#else) = &mut self.r#else {
+            r#else.result.expand_stars(stars)?;
+        }
+        Ok(())
+    }
+
+    pub fn infer_name(&self) -> Option<&Ident> {
+        None
+    }
+
+    pub fn infer_type(&self, scope_id: ScopeId) -> Option<InferredType<'_>> {
+        // Without an `else` branch, the expression evaluates to `null` if no branch matches.
+        let mut nullable = self.r#else.is_none();
+        let mut branches = Vec::new();
+        for result in self
+            .branches
+            .iter()
+            .map(|branch| &branch.result)
+            .chain(self.r#else.iter().map(|r#else| r#else.result.as_ref()))
+        {
+            if let Expr::Lit(Lit::Null(..)) = result {
+                nullable = true;
+            } else {
+                branches.push(result.infer_type(scope_id)?);
+            }
+        }
+        if branches.is_empty() {
+            return None;
+        }
+        Some(InferredType::Same {
+            operands: branches,
+            nullable,
+        })
+    }
+
+    pub fn span(&self) -> Span {
+        self._case
+            .span
+            .join(self._end.span)
+            .unwrap_or(self._case.span)
+    }
+}
+
+impl Parse for Case {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let _case = input.parse()?;
+        let operand = (!input.peek(keyword::when))
+            .then(|| input.parse())
+            .transpose()?;
+
+        let mut branches = Vec::new();
+        while input.peek(keyword::when) {
+            branches.push(input.parse()?);
+        }
+        if branches.is_empty() {
+            return Err(syn::Error::new(
+                input.span(),
+                "case expression requires at least one `when` branch",
+            ));
+        }
+
+        Ok(Self {
+            _case,
+            operand,
+            branches,
+            r#else: input
+                .peek(Token![else])
+                .then(|| input.parse())
+                .transpose()?,
+            _end: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Case {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let operand = QuoteOption(self.operand.as_ref().map(|operand| quote! { &#operand }));
+        let branches = self.branches.iter();
+        let r#else = QuoteOption(
+            self.r#else
+                .as_ref()
+                .map(|r#else| &r#else.result)
+                .map(|result| quote! { &#result }),
+        );
+        quote! {
+            ::kosame::repr::expr::Case::new(#operand, &[#(#branches),*], #r#else)
+        }
+        .to_tokens(tokens);
+    }
+}
+
+pub struct CaseBranch {
+    pub _when: keyword::when,
+    pub condition: Expr,
+    pub _then: keyword::then,
+    pub result: Expr,
+}
+
+impl Parse for CaseBranch {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            _when: input.parse()?,
+            condition: input.parse()?,
+            _then: input.parse()?,
+            result: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for CaseBranch {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let condition = &self.condition;
+        let result = &self.result;
+        quote! {
+            ::kosame::repr::expr::CaseBranch::new(#condition, #result)
+        }
+        .to_tokens(tokens);
+    }
+}
+
+pub struct CaseElse {
+    pub _else: Token![else],
+    pub result: Box<Expr>,
+}
+
+impl Parse for CaseElse {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            _else: input.parse()?,
+            result: input.parse()?,
+        })
+    }
+}
@@ -3,11 +3,14 @@ use quote::{ToTokens, quote};
 use syn::{
     Ident, Token, parenthesized,
     parse::{Parse, ParseStream},
+    parse_quote,
     spanned::Spanned,
     token::Paren,
 };
 
-use crate::{data_type::DataType, inferred_type::InferredType, keyword, scopes::ScopeId};
+use crate::{
+    data_type::DataType, inferred_type::InferredType, keyword, scopes::ScopeId, stars::StarExpander,
+};
 
 use super::{Expr, Visitor};
 
@@ -28,15 +31,21 @@ impl Cast {
         self.value.accept(visitor);
     }
 
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        self.value.expand_stars(stars)
+    }
+
     pub fn infer_name(&self) -> Option<&Ident> {
         self.value.infer_name()
     }
 
-    pub fn infer_type(&self, _scope_id: ScopeId) -> Option<InferredType<'_>> {
-        // Difficulty detecting nullability
-        // Some(InferredType::DataType(self.data_type.clone()))
-
-        None
+    pub fn infer_type(&self, scope_id: ScopeId) -> Option<InferredType<'_>> {
+        let rust_type = self.data_type.rust_type()?;
+        Some(InferredType::derived(
+            parse_quote!(#rust_type),
+            [self.value.as_ref()],
+            scope_id,
+        ))
     }
 
     pub fn span(&self) -> Span {
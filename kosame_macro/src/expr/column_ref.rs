@@ -1,4 +1,4 @@
-use crate::{inferred_type::InferredType, scopes::ScopeId};
+use crate::{inferred_type::InferredType, scopes::ScopeId, stars::StarExpander};
 
 use super::Visitor;
 use proc_macro2::{Span, TokenStream};
@@ -16,6 +16,10 @@ pub struct ColumnRef {
 impl ColumnRef {
     pub fn accept<'a>(&'a self, _visitor: &mut impl Visitor<'a>) {}
 
+    pub fn expand_stars(&mut self, _stars: &mut StarExpander) -> syn::Result<()> {
+        Ok(())
+    }
+
     pub fn infer_name(&self) -> Option<&Ident> {
         Some(&self.name)
     }
@@ -0,0 +1,71 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{
+    Ident, parenthesized,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    spanned::Spanned,
+};
+
+use crate::{
+    command::Command, inferred_type::InferredType, keyword, scopes::ScopeId, stars::StarExpander,
+};
+
+use super::Visitor;
+
+pub struct Exists {
+    pub _exists: keyword::exists,
+    pub paren: syn::token::Paren,
+    pub command: Box<Command>,
+}
+
+impl Exists {
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::exists) && input.peek2(syn::token::Paren)
+    }
+
+    pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        visitor.visit_subquery(&self.command);
+        self.command.accept(visitor);
+    }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        self.command.expand_stars(stars)
+    }
+
+    pub fn infer_name(&self) -> Option<&Ident> {
+        None
+    }
+
+    pub fn infer_type(&self, _scope_id: ScopeId) -> Option<InferredType<'_>> {
+        Some(InferredType::NotNull(parse_quote!(bool)))
+    }
+
+    pub fn span(&self) -> Span {
+        self._exists
+            .span
+            .join(self.paren.span.span())
+            .unwrap_or(self._exists.span)
+    }
+}
+
+impl Parse for Exists {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        Ok(Self {
+            _exists: input.parse()?,
+            paren: parenthesized!(content in input),
+            command: content.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Exists {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let command = &self.command;
+        quote! {
+            ::kosame::repr::expr::Exists::new(&#command)
+        }
+        .to_tokens(tokens);
+    }
+}
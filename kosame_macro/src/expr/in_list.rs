@@ -0,0 +1,145 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{
+    Ident, Token, parenthesized,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    punctuated::Punctuated,
+    spanned::Spanned,
+};
+
+use crate::{
+    clause::{Select, With},
+    command::Command,
+    inferred_type::InferredType,
+    keyword,
+    scopes::ScopeId,
+    stars::StarExpander,
+};
+
+use super::{Expr, Visitor};
+
+pub struct InList {
+    pub lhs: Box<Expr>,
+    pub _not: Option<keyword::not>,
+    pub _in: Token![in],
+    pub paren: syn::token::Paren,
+    pub items: InListItems,
+}
+
+impl InList {
+    pub const PRECEDENCE: u32 = 6;
+
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(Token![in]) || (input.peek(keyword::not) && input.peek2(Token![in]))
+    }
+
+    /// Parses the remainder of an `in` expression whose left hand side has already been parsed.
+    pub fn parse_rest(lhs: Expr, input: ParseStream) -> syn::Result<Self> {
+        let content;
+        Ok(Self {
+            lhs: Box::new(lhs),
+            _not: input.parse()?,
+            _in: input.parse()?,
+            paren: parenthesized!(content in input),
+            items: content.parse()?,
+        })
+    }
+
+    pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        self.lhs.accept(visitor);
+        match &self.items {
+            InListItems::Exprs(exprs) => {
+                for expr in exprs {
+                    expr.accept(visitor);
+                }
+            }
+            InListItems::Subquery(command) => {
+                visitor.visit_subquery(command);
+                command.accept(visitor);
+            }
+        }
+    }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        self.lhs.expand_stars(stars)?;
+        match &mut self.items {
+            InListItems::Exprs(exprs) => {
+                for expr in exprs {
+                    expr.expand_stars(stars)?;
+                }
+                Ok(())
+            }
+            InListItems::Subquery(command) => command.expand_stars(stars),
+        }
+    }
+
+    pub fn infer_name(&self) -> Option<&Ident> {
+        None
+    }
+
+    pub fn infer_type(&self, scope_id: ScopeId) -> Option<InferredType<'_>> {
+        match &self.items {
+            InListItems::Exprs(exprs) => Some(InferredType::derived(
+                parse_quote!(bool),
+                std::iter::once(self.lhs.as_ref()).chain(exprs),
+                scope_id,
+            )),
+            // The selected values of the subquery may be `null`.
+            InListItems::Subquery(_) => Some(InferredType::Derived {
+                rust_type: parse_quote!(bool),
+                operands: self.lhs.infer_type(scope_id).into_iter().collect(),
+                nullable: true,
+            }),
+        }
+    }
+
+    pub fn span(&self) -> Span {
+        self.lhs
+            .span()
+            .join(self.paren.span.span())
+            .unwrap_or(self.lhs.span())
+    }
+}
+
+impl ToTokens for InList {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let lhs = &self.lhs;
+        let not = self._not.is_some();
+        let items = match &self.items {
+            InListItems::Exprs(exprs) => {
+                let exprs = exprs.iter();
+                quote! { ::kosame::repr::expr::InListItems::Exprs(&[#(#exprs),*]) }
+            }
+            InListItems::Subquery(command) => {
+                quote! { ::kosame::repr::expr::InListItems::Subquery(&#command) }
+            }
+        };
+        quote! {
+            ::kosame::repr::expr::InList::new(&#lhs, #not, #items)
+        }
+        .to_tokens(tokens);
+    }
+}
+
+pub enum InListItems {
+    Exprs(Punctuated<Expr, Token![,]>),
+    Subquery(Box<Command>),
+}
+
+impl Parse for InListItems {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if With::peek(input) || Select::peek(input) {
+            Ok(Self::Subquery(input.parse()?))
+        } else {
+            let exprs = Punctuated::parse_terminated(input)?;
+            if exprs.is_empty() {
+                return Err(syn::Error::new(
+                    input.span(),
+                    "expected at least one expression or a subquery",
+                ));
+            }
+            Ok(Self::Exprs(exprs))
+        }
+    }
+}
@@ -1,11 +1,12 @@
-use crate::{inferred_type::InferredType, keyword, scopes::ScopeId};
+use crate::{inferred_type::InferredType, keyword, scopes::ScopeId, stars::StarExpander};
 
 use super::Visitor;
 use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
 use syn::{
-    Ident,
+    Ident, Type,
     parse::{Parse, ParseStream},
+    parse_quote,
     spanned::Spanned,
 };
 
@@ -21,12 +22,32 @@ pub enum Lit {
 impl Lit {
     pub fn accept<'a>(&'a self, _visitor: &mut impl Visitor<'a>) {}
 
+    pub fn expand_stars(&mut self, _stars: &mut StarExpander) -> syn::Result<()> {
+        Ok(())
+    }
+
     pub fn infer_name(&self) -> Option<&Ident> {
         None
     }
 
     pub fn infer_type(&self, _scope_id: ScopeId) -> Option<InferredType<'_>> {
-        None
+        let rust_type: Type = match self {
+            Self::Int(inner) => match inner.suffix() {
+                "" => match inner.base10_parse::<i32>() {
+                    Ok(_) => parse_quote!(i32),
+                    Err(_) => parse_quote!(i64),
+                },
+                suffix => syn::parse_str(suffix).ok()?,
+            },
+            Self::Float(inner) => match inner.suffix() {
+                "" => parse_quote!(f64),
+                suffix => syn::parse_str(suffix).ok()?,
+            },
+            Self::Str(_) => parse_quote!(::std::string::String),
+            Self::Bool(_) => parse_quote!(bool),
+            Self::Null(_) => return None,
+        };
+        Some(InferredType::Literal(rust_type))
     }
 
     pub fn span(&self) -> Span {
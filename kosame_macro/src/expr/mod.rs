@@ -1,21 +1,33 @@
+mod any;
+mod between;
 mod binary;
 mod bind_param;
 mod call;
+mod case;
 mod cast;
 mod column_ref;
+mod exists;
+mod in_list;
 mod lit;
 mod paren;
 mod raw;
+mod subquery;
 mod unary;
 
+pub use any::*;
+pub use between::*;
 pub use binary::*;
 pub use bind_param::*;
 pub use call::*;
+pub use case::*;
 pub use cast::*;
 pub use column_ref::*;
+pub use exists::*;
+pub use in_list::*;
 pub use lit::*;
 pub use paren::*;
 pub use raw::*;
+pub use subquery::*;
 pub use unary::*;
 
 use proc_macro2::{Span, TokenStream};
@@ -26,31 +38,43 @@ use syn::{
     spanned::Spanned,
 };
 
-use crate::{inferred_type::InferredType, scopes::ScopeId, visitor::Visitor};
+use crate::{inferred_type::InferredType, scopes::ScopeId, stars::StarExpander, visitor::Visitor};
 
 pub enum Expr {
+    Any(Any),
+    Between(Between),
     Binary(Binary),
     BindParam(BindParam),
     Call(Call),
+    Case(Case),
     Cast(Cast),
     ColumnRef(ColumnRef),
+    Exists(Exists),
+    InList(InList),
     Lit(Lit),
     Paren(Paren),
     Raw(Raw),
+    Subquery(Subquery),
     Unary(Unary),
 }
 
 macro_rules! variants {
     ($macro:ident!()) => {
         $macro!(
+            Any
+            Between
             Binary
             BindParam
             Call
+            Case
             Cast
             ColumnRef
+            Exists
+            InList
             Lit
             Paren
             Raw
+            Subquery
             Unary
         )
     };
@@ -69,6 +93,18 @@ impl Expr {
         variants!(branches!());
     }
 
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        macro_rules! branches {
+            ($($variant:ident)*) => {
+                match self {
+                    $(Self::$variant(inner) => inner.expand_stars(stars)),*
+                }
+            };
+        }
+
+        variants!(branches!())
+    }
+
     pub fn infer_name(&self) -> Option<&Ident> {
         macro_rules! branches {
             ($($variant:ident)*) => {
@@ -94,7 +130,9 @@ impl Expr {
     }
 
     fn parse_prefix(input: ParseStream) -> syn::Result<Expr> {
-        if input.peek(syn::token::Paren) {
+        if Subquery::peek(input) {
+            Ok(Expr::Subquery(input.parse()?))
+        } else if input.peek(syn::token::Paren) {
             Ok(Expr::Paren(input.parse()?))
         } else if BindParam::peek(input) {
             Ok(Expr::BindParam(input.parse()?))
@@ -107,6 +145,10 @@ impl Expr {
                 op,
                 Self::parse_expr(input, precedence)?,
             )))
+        } else if Exists::peek(input) {
+            Ok(Expr::Exists(input.parse()?))
+        } else if Case::peek(input) {
+            Ok(Expr::Case(input.parse()?))
         } else if Cast::peek(input) {
             Ok(Expr::Cast(input.parse()?))
         } else if input.fork().parse::<Lit>().is_ok() {
@@ -123,7 +165,35 @@ impl Expr {
     fn parse_expr(input: ParseStream, min_precedence: u32) -> syn::Result<Expr> {
         let mut lhs = Self::parse_prefix(input)?;
 
-        while let Some(bin_op) = BinOp::peek(input) {
+        loop {
+            if Any::peek(input) {
+                if Any::PRECEDENCE < min_precedence {
+                    break;
+                }
+                lhs = Expr::Any(Any::parse_rest(lhs, input)?);
+                continue;
+            }
+
+            if InList::peek(input) {
+                if InList::PRECEDENCE < min_precedence {
+                    break;
+                }
+                lhs = Expr::InList(InList::parse_rest(lhs, input)?);
+                continue;
+            }
+
+            if Between::peek(input) {
+                if Between::PRECEDENCE < min_precedence {
+                    break;
+                }
+                lhs = Expr::Between(Between::parse_rest(lhs, input)?);
+                continue;
+            }
+
+            let Some(bin_op) = BinOp::peek(input) else {
+                break;
+            };
+
             let precedence = bin_op.precedence();
             if precedence < min_precedence {
                 break;
@@ -1,5 +1,6 @@
 use crate::inferred_type::InferredType;
 use crate::scopes::ScopeId;
+use crate::stars::StarExpander;
 
 use super::Expr;
 use super::Visitor;
@@ -18,7 +19,13 @@ pub struct Paren {
 }
 
 impl Paren {
-    pub fn accept<'a>(&'a self, _visitor: &mut impl Visitor<'a>) {}
+    pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        self.expr.accept(visitor);
+    }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        self.expr.expand_stars(stars)
+    }
 
     pub fn span(&self) -> Span {
         self.paren.span.span()
@@ -0,0 +1,121 @@
+use proc_macro2::{Delimiter, Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{
+    Ident, parenthesized,
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+};
+
+use crate::{
+    command::{Command, CommandType},
+    inferred_type::InferredType,
+    scopes::ScopeId,
+    stars::StarExpander,
+};
+
+use super::{Expr, Visitor};
+
+/// A scalar subquery like `(select count(*) from comments where comments.post_id = posts.id)`,
+/// which selects a single field.
+pub struct Subquery {
+    pub paren: syn::token::Paren,
+    pub command: Box<Command>,
+}
+
+impl Subquery {
+    pub fn peek(input: ParseStream) -> bool {
+        input
+            .cursor()
+            .group(Delimiter::Parenthesis)
+            .and_then(|(content, ..)| content.ident())
+            .is_some_and(|(ident, _)| ident == "select" || ident == "with")
+    }
+
+    pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        visitor.visit_subquery(&self.command);
+        self.command.accept(visitor);
+    }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        self.command.expand_stars(stars)?;
+        let field_count = self
+            .command
+            .fields()
+            .map_or(0, |fields| fields.iter().count());
+        // The field count is checked once `*` has been expanded.
+        let is_expanded = self
+            .command
+            .fields()
+            .is_none_or(|fields| fields.is_expanded());
+        if is_expanded && field_count != 1 {
+            return Err(syn::Error::new(
+                self.paren.span.join(),
+                format!("scalar subqueries must select exactly one field, found {field_count}"),
+            ));
+        }
+        Ok(())
+    }
+
+    fn field(&self) -> Option<&crate::clause::Field> {
+        self.command.fields()?.iter().next()
+    }
+
+    pub fn infer_name(&self) -> Option<&Ident> {
+        self.field()?.infer_name()
+    }
+
+    pub fn infer_type(&self, _scope_id: ScopeId) -> Option<InferredType<'_>> {
+        let field = self.field()?;
+        let inferred_type = field.infer_type(self.command.scope_id)?;
+        // A subquery that selects no rows is `null`, except for aggregates without `group by`,
+        // which always select exactly one row.
+        if self.is_single_row() {
+            return Some(inferred_type);
+        }
+        Some(InferredType::Same {
+            operands: vec![inferred_type],
+            nullable: true,
+        })
+    }
+
+    fn is_single_row(&self) -> bool {
+        let CommandType::Select(select) = &self.command.command_type else {
+            return false;
+        };
+        let aggregate = matches!(
+            self.field().map(|field| &field.expr),
+            Some(Expr::Call(call)) if call.is_aggregate()
+        );
+        aggregate
+            && select.group_by.is_none()
+            && select.having.is_none()
+            && select.set_operations.is_empty()
+            && select.limit.is_none()
+            && select.offset.is_none()
+    }
+
+    pub fn span(&self) -> Span {
+        self.paren.span.span()
+    }
+}
+
+impl Parse for Subquery {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let paren = parenthesized!(content in input);
+        Ok(Self {
+            paren,
+            command: content.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Subquery {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let command = &self.command;
+        quote! {
+            ::kosame::repr::expr::Subquery::new(&#command)
+        }
+        .to_tokens(tokens);
+    }
+}
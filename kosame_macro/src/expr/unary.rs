@@ -3,9 +3,10 @@ use quote::{ToTokens, quote};
 use syn::{
     Ident,
     parse::{Parse, ParseStream},
+    parse_quote,
 };
 
-use crate::{inferred_type::InferredType, keyword, scopes::ScopeId};
+use crate::{inferred_type::InferredType, keyword, scopes::ScopeId, stars::StarExpander};
 
 use super::{Expr, Visitor};
 
@@ -26,12 +27,22 @@ impl Unary {
         self.operand.accept(visitor);
     }
 
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        self.operand.expand_stars(stars)
+    }
+
     pub fn infer_name(&self) -> Option<&Ident> {
         None
     }
 
-    pub fn infer_type(&self, _scope_id: ScopeId) -> Option<InferredType<'_>> {
-        None
+    pub fn infer_type(&self, scope_id: ScopeId) -> Option<InferredType<'_>> {
+        match self.op {
+            UnaryOp::Not(_) => Some(InferredType::derived(
+                parse_quote!(bool),
+                [self.operand.as_ref()],
+                scope_id,
+            )),
+        }
     }
 }
 
@@ -1,7 +1,8 @@
-use syn::{Ident, Path, parse_quote};
+use syn::{Ident, Path, Type, parse_quote};
 
 use crate::{
     correlations::{CorrelationId, Correlations},
+    expr::Expr,
     part::TablePath,
     path_ext::PathExt,
     scopes::{ScopeId, Scopes},
@@ -24,6 +25,161 @@ pub enum InferredType<'a> {
         table_path: &'a TablePath,
         column: &'a Ident,
     },
+    /// A literal, which is never `null`. In arithmetic and similar expressions, literals adopt the
+    /// type of the other operands if the database keeps that type, so that `bigint_column + 1` is
+    /// an `i64`.
+    Literal(Type),
+    /// A type that is never `null`, e.g. the result of `count(...)` or `is null`.
+    NotNull(Type),
+    /// A type that is `null` if any of the operands is `null`, or if `nullable` is set, e.g. the
+    /// result of a comparison or a cast.
+    Derived {
+        rust_type: Type,
+        operands: Vec<InferredType<'a>>,
+        nullable: bool,
+    },
+    /// Operands which must all have the same type, e.g. of `+` or the branches of a `case`
+    /// expression. The result is `null` if any of the operands is `null`, or if `nullable` is set.
+    Same {
+        operands: Vec<InferredType<'a>>,
+        nullable: bool,
+    },
+    /// Operands of `coalesce`, which must all have the same type. The result is `null` only if all
+    /// of the operands are `null`.
+    Coalesce {
+        operands: Vec<InferredType<'a>>,
+    },
+    /// The result of `sum(...)`, whose type depends on the type of the operand. The result is
+    /// `null` if the operand is `null`.
+    Sum(Box<InferredType<'a>>),
+    /// The result of an aggregate function like `max(...)`, which is `null` when aggregating zero
+    /// rows. This can only happen if the command in the given scope has no `group by` clause.
+    Aggregate {
+        inner: Box<InferredType<'a>>,
+        scope_id: ScopeId,
+    },
+    /// A type that is known not to be `null`, e.g. of a column that is checked with `is not null`
+    /// in the `where` clause.
+    Narrowed(Box<InferredType<'a>>),
+}
+
+impl<'a> InferredType<'a> {
+    /// A [`InferredType::Derived`] type with the nullability of the given expressions. Expressions
+    /// whose type cannot be inferred, like bind parameters, are assumed to be nullable.
+    pub fn derived(
+        rust_type: Type,
+        exprs: impl IntoIterator<Item = &'a Expr>,
+        scope_id: ScopeId,
+    ) -> Self {
+        let (operands, nullable) = infer_operands(exprs, scope_id);
+        Self::Derived {
+            rust_type,
+            operands,
+            nullable,
+        }
+    }
+
+    /// A [`InferredType::Same`] type of the given expressions, with the same treatment of
+    /// expressions whose type cannot be inferred as [`InferredType::derived`].
+    pub fn same(exprs: impl IntoIterator<Item = &'a Expr>, scope_id: ScopeId) -> Self {
+        let (operands, nullable) = infer_operands(exprs, scope_id);
+        Self::Same { operands, nullable }
+    }
+}
+
+/// The inferred types of the given expressions, and whether any of them could not be inferred.
+fn infer_operands<'a>(
+    exprs: impl IntoIterator<Item = &'a Expr>,
+    scope_id: ScopeId,
+) -> (Vec<InferredType<'a>>, bool) {
+    let mut operands = Vec::new();
+    let mut nullable = false;
+    for expr in exprs {
+        match expr.infer_type(scope_id) {
+            Some(operand) => operands.push(operand),
+            None => nullable = true,
+        }
+    }
+    (operands, nullable)
+}
+
+/// Whether a resolved type may be `null`.
+enum Nullability {
+    NotNull,
+    Nullable,
+    /// Depends on the constraints of a table column, which are only known to the compiler. The
+    /// type implements `kosame::infer::Nullability`.
+    Of(Box<Type>),
+}
+
+impl Nullability {
+    /// Nullable if any of the given nullabilities is nullable.
+    fn any(nullabilities: impl IntoIterator<Item = Nullability>) -> Self {
+        let mut result = Self::NotNull;
+        for nullability in nullabilities {
+            result = match (result, nullability) {
+                (Self::Nullable, _) | (_, Self::Nullable) => Self::Nullable,
+                (Self::NotNull, other) | (other, Self::NotNull) => other,
+                (Self::Of(lhs), Self::Of(rhs)) => Self::Of(Box::new(
+                    parse_quote!(<#lhs as ::kosame::infer::Or<#rhs>>::Output),
+                )),
+            };
+        }
+        result
+    }
+
+    /// Nullable only if all of the given nullabilities are nullable.
+    fn all(nullabilities: impl IntoIterator<Item = Nullability>) -> Self {
+        let mut result = Self::Nullable;
+        for nullability in nullabilities {
+            result = match (result, nullability) {
+                (Self::NotNull, _) | (_, Self::NotNull) => Self::NotNull,
+                (Self::Nullable, other) | (other, Self::Nullable) => other,
+                (Self::Of(lhs), Self::Of(rhs)) => Self::Of(Box::new(
+                    parse_quote!(<#lhs as ::kosame::infer::And<#rhs>>::Output),
+                )),
+            };
+        }
+        result
+    }
+}
+
+struct ResolvedType {
+    not_null: Type,
+    nullability: Nullability,
+    /// The complete type, if it is spelled differently than the combination of `not_null` and
+    /// `nullability`, e.g. `columns::title::Type` for a table column.
+    complete: Option<Type>,
+    literal: bool,
+}
+
+impl ResolvedType {
+    fn new(not_null: Type, nullability: Nullability) -> Self {
+        Self {
+            not_null,
+            nullability,
+            complete: None,
+            literal: false,
+        }
+    }
+
+    fn nullable(self) -> Self {
+        Self::new(self.not_null, Nullability::Nullable)
+    }
+
+    fn into_type(self) -> Type {
+        if let Some(complete) = self.complete {
+            return complete;
+        }
+        let not_null = self.not_null;
+        match self.nullability {
+            Nullability::NotNull => not_null,
+            Nullability::Nullable => parse_quote!(::core::option::Option<#not_null>),
+            Nullability::Of(nullability) => {
+                parse_quote!(<#nullability as ::kosame::infer::Nullability>::Apply<#not_null>)
+            }
+        }
+    }
 }
 
 pub fn resolve_type(
@@ -31,18 +187,33 @@ pub fn resolve_type(
     scopes: &Scopes<'_>,
     correlation_id: CorrelationId,
     column: &Ident,
-) -> Option<Path> {
-    let mut inferred_type = correlations.infer_type(correlation_id, column)?;
+) -> Option<Type> {
+    let inferred_type = correlations.infer_type(correlation_id, column)?;
+    resolve_inferred_type(correlations, scopes, inferred_type).map(ResolvedType::into_type)
+}
+
+fn resolve_inferred_type<'a>(
+    correlations: &'a Correlations<'_>,
+    scopes: &Scopes<'_>,
+    mut inferred_type: InferredType<'a>,
+) -> Option<ResolvedType> {
     let mut combined_nullable = false;
     for _ in 0..1024 {
-        match inferred_type {
-            InferredType::RustType(rust_type) => return Some(rust_type.clone()),
+        let resolved = match inferred_type {
+            // Type overrides are taken as they are, including their nullability.
+            InferredType::RustType(rust_type) => {
+                return Some(ResolvedType::new(
+                    parse_quote!(#rust_type),
+                    Nullability::NotNull,
+                ));
+            }
             InferredType::Scope {
                 scope_id,
                 table,
                 column,
             } => {
                 inferred_type = scopes.infer_type(scope_id, table, column)?;
+                continue;
             }
             InferredType::Correlation {
                 correlation_id,
@@ -51,19 +222,124 @@ pub fn resolve_type(
             } => {
                 combined_nullable = combined_nullable || nullable;
                 inferred_type = correlations.infer_type(correlation_id, column)?;
+                continue;
             }
             InferredType::TableColumn { table_path, column } => {
                 let table_path = table_path.as_path().to_call_site(1);
-                match combined_nullable {
-                    true => {
-                        return Some(
-                            parse_quote!(::core::option::Option<#table_path::columns::#column::TypeNotNull>),
-                        );
-                    }
-                    false => return Some(parse_quote!(#table_path::columns::#column::Type)),
+                ResolvedType {
+                    not_null: parse_quote!(#table_path::columns::#column::TypeNotNull),
+                    nullability: Nullability::Of(Box::new(
+                        parse_quote!(#table_path::columns::#column::Nullability),
+                    )),
+                    complete: Some(parse_quote!(#table_path::columns::#column::Type)),
+                    literal: false,
                 }
             }
-        }
+            InferredType::Literal(rust_type) => ResolvedType {
+                literal: true,
+                ..ResolvedType::new(rust_type, Nullability::NotNull)
+            },
+            InferredType::NotNull(rust_type) => ResolvedType::new(rust_type, Nullability::NotNull),
+            InferredType::Derived {
+                rust_type,
+                operands,
+                nullable,
+            } => {
+                let operands = resolve_operands(correlations, scopes, operands)?;
+                let nullability = Nullability::any(
+                    operands
+                        .into_iter()
+                        .map(|operand| operand.nullability)
+                        .chain(nullable.then_some(Nullability::Nullable)),
+                );
+                ResolvedType::new(rust_type, nullability)
+            }
+            InferredType::Same { operands, nullable } => {
+                let operands = resolve_operands(correlations, scopes, operands)?;
+                let not_null = same_type(&operands)?;
+                let nullability = Nullability::any(
+                    operands
+                        .into_iter()
+                        .map(|operand| operand.nullability)
+                        .chain(nullable.then_some(Nullability::Nullable)),
+                );
+                ResolvedType::new(not_null, nullability)
+            }
+            InferredType::Coalesce { operands } => {
+                let operands = resolve_operands(correlations, scopes, operands)?;
+                let not_null = same_type(&operands)?;
+                let nullability =
+                    Nullability::all(operands.into_iter().map(|operand| operand.nullability));
+                ResolvedType::new(not_null, nullability)
+            }
+            InferredType::Sum(operand) => {
+                let operand = resolve_inferred_type(correlations, scopes, *operand)?;
+                let not_null = operand.not_null;
+                ResolvedType::new(
+                    parse_quote!(<#not_null as ::kosame::infer::Sum>::Output),
+                    operand.nullability,
+                )
+            }
+            InferredType::Aggregate { inner, scope_id } => {
+                let inner = resolve_inferred_type(correlations, scopes, *inner)?;
+                match scopes.is_grouped(scope_id) {
+                    true => ResolvedType {
+                        literal: false,
+                        ..inner
+                    },
+                    false => inner.nullable(),
+                }
+            }
+            InferredType::Narrowed(inner) => {
+                let inner = resolve_inferred_type(correlations, scopes, *inner)?;
+                ResolvedType {
+                    nullability: Nullability::NotNull,
+                    complete: None,
+                    ..inner
+                }
+            }
+        };
+
+        return Some(match combined_nullable {
+            true => resolved.nullable(),
+            false => resolved,
+        });
     }
     panic!("infinite type inference loop detected");
 }
+
+fn resolve_operands<'a>(
+    correlations: &'a Correlations<'_>,
+    scopes: &Scopes<'_>,
+    operands: Vec<InferredType<'a>>,
+) -> Option<Vec<ResolvedType>> {
+    operands
+        .into_iter()
+        .map(|operand| resolve_inferred_type(correlations, scopes, operand))
+        .collect()
+}
+
+/// The type shared by all operands. Literals adopt the type of the other operands if the
+/// database keeps it, see [`kosame::infer::WithLiteral`], and must otherwise all have the same
+/// type, e.g. `1 + 2.5` cannot be inferred. Every further operand must have the same type, which
+/// is checked by the compiler.
+fn same_type(operands: &[ResolvedType]) -> Option<Type> {
+    let (literals, others): (Vec<_>, Vec<_>) = operands.iter().partition(|operand| operand.literal);
+    let mut types = others.into_iter().map(|operand| &operand.not_null);
+    let Some(first) = types.next() else {
+        let first = &literals.first()?.not_null;
+        return literals
+            .iter()
+            .all(|literal| literal.not_null == *first)
+            .then(|| first.clone());
+    };
+    let mut result = first.clone();
+    for operand_type in types {
+        result = parse_quote!(<#result as ::kosame::infer::SameType<#operand_type>>::Type);
+    }
+    for literal in literals {
+        let literal_type = &literal.not_null;
+        result = parse_quote!(<#result as ::kosame::infer::WithLiteral<#literal_type>>::Type);
+    }
+    Some(result)
+}
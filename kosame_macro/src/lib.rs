@@ -4,6 +4,7 @@ mod clause;
 mod command;
 mod correlations;
 mod data_type;
+mod dialect_check;
 mod driver;
 mod expr;
 mod inferred_type;
@@ -15,10 +16,13 @@ mod quote_option;
 mod row;
 mod schema;
 mod scopes;
+mod stars;
 mod statement;
 mod unique_macro;
 mod visitor;
 
+use attribute::{CustomMeta, MetaLocation};
+use driver::Driver;
 use proc_macro_error::proc_macro_error;
 use quote::{ToTokens, quote};
 use syn::{DeriveInput, parse_macro_input};
@@ -45,69 +49,109 @@ pub fn query(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
 }
 
 #[proc_macro_error]
-#[proc_macro_derive(Row, attributes(star))]
+#[proc_macro_derive(Row, attributes(star, kosame))]
 pub fn derive_row(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(tokens as DeriveInput);
     let name = &input.ident;
     let syn::Data::Struct(data) = input.data else {
         proc_macro_error::abort_call_site!("#[derive(Row)] can only be used on structs.");
     };
+    let driver = match CustomMeta::parse_attrs(&input.attrs, MetaLocation::Row) {
+        Ok(custom_meta) => custom_meta.driver(),
+        Err(error) => return error.into_compile_error().into(),
+    };
 
     let mut tokens = proc_macro2::TokenStream::new();
 
-    #[cfg(any(feature = "postgres", feature = "tokio-postgres"))]
-    {
-        let fields = data.fields.iter().enumerate().map(|(index, field)| {
-            let name = &field.ident;
+    match driver {
+        Driver::Postgres | Driver::TokioPostgres => {
+            let fields = data.fields.iter().enumerate().map(|(index, field)| {
+                let name = &field.ident;
+                quote! {
+                    #name: row.get(#index)
+                }
+            });
+
             quote! {
-                #name: row.get(#index)
+                impl From<&::kosame::driver::postgres_types::Row> for #name {
+                    fn from(row: &::kosame::driver::postgres_types::Row) -> Self {
+                        Self {
+                            #(#fields),*
+                        }
+                    }
+                }
             }
-        });
+            .to_tokens(&mut tokens);
+
+            let field_count = data.fields.len() as i32;
+            let fields = data.fields.iter().map(|field| {
+                let name = &field.ident;
+                quote! {
+                    #name: ::kosame::driver::postgres_types::record_field_from_sql(&raw, &mut offset)?
+                }
+            });
+
+            quote! {
+                impl<'a> ::kosame::driver::postgres_types::FromSql<'a> for #name {
+                    fn accepts(ty: &::kosame::driver::postgres_types::Type) -> bool {
+                        ty.name() == "record"
+                    }
+
+                    fn from_sql(
+                        ty: &::kosame::driver::postgres_types::Type,
+                        raw: &[u8],
+                    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+                        let column_count = ::kosame::driver::postgres_types::int4_from_sql(&raw[..4])?;
+                        assert_eq!(column_count, #field_count);
 
-        quote! {
-            impl From<&::kosame::driver::postgres_types::Row> for #name {
-                fn from(row: &::kosame::driver::postgres_types::Row) -> Self {
-                    Self {
-                        #(#fields),*
+                        let mut offset = 4;
+
+                        Ok(Self {
+                            #(#fields),*
+                        })
                     }
                 }
             }
+            .to_tokens(&mut tokens);
         }
-        .to_tokens(&mut tokens);
-    }
+        Driver::Rusqlite => {
+            let fields = data.fields.iter().enumerate().map(|(index, field)| {
+                let name = &field.ident;
+                quote! {
+                    #name: row.get(#index)
+                }
+            });
 
-    #[cfg(any(feature = "postgres", feature = "tokio-postgres"))]
-    {
-        let field_count = data.fields.len() as i32;
-        let fields = data.fields.iter().map(|field| {
-            let name = &field.ident;
             quote! {
-                #name: ::kosame::driver::postgres_types::record_field_from_sql(&raw, &mut offset)?
+                impl From<&::kosame::driver::rusqlite::Row> for #name {
+                    fn from(row: &::kosame::driver::rusqlite::Row) -> Self {
+                        Self {
+                            #(#fields),*
+                        }
+                    }
+                }
             }
-        });
-
-        quote! {
-            impl<'a> ::kosame::driver::postgres_types::FromSql<'a> for #name {
-                fn accepts(ty: &::kosame::driver::postgres_types::Type) -> bool {
-                    ty.name() == "record"
+            .to_tokens(&mut tokens);
+        }
+        Driver::Mysql => {
+            let fields = data.fields.iter().enumerate().map(|(index, field)| {
+                let name = &field.ident;
+                quote! {
+                    #name: ::kosame::driver::mysql_types::get(row, #index)
                 }
+            });
 
-                fn from_sql(
-                    ty: &::kosame::driver::postgres_types::Type,
-                    raw: &[u8],
-                ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
-                    let column_count = ::kosame::driver::postgres_types::int4_from_sql(&raw[..4])?;
-                    assert_eq!(column_count, #field_count);
-
-                    let mut offset = 4;
-
-                    Ok(Self {
-                        #(#fields),*
-                    })
+            quote! {
+                impl From<&::kosame::driver::mysql_types::Row> for #name {
+                    fn from(row: &::kosame::driver::mysql_types::Row) -> Self {
+                        Self {
+                            #(#fields),*
+                        }
+                    }
                 }
             }
+            .to_tokens(&mut tokens);
         }
-        .to_tokens(&mut tokens);
     }
 
     tokens.into()
@@ -3,7 +3,6 @@ use quote::{ToTokens, quote};
 use syn::{
     Ident, Token,
     parse::{Parse, ParseStream},
-    spanned::Spanned,
 };
 
 use crate::{clause::peek_clause, keyword, part::ColumnList, quote_option::QuoteOption};
@@ -44,20 +43,10 @@ impl Parse for TableAlias {
         Ok(Self {
             _as_token: input.peek(Token![as]).then(|| input.parse()).transpose()?,
             name: input.parse()?,
-            columns: {
-                let columns = input
-                    .peek(syn::token::Paren)
-                    .then(|| input.parse::<ColumnList>())
-                    .transpose()?;
-                if let Some(columns) = columns {
-                    return Err(syn::Error::new(
-                        columns._paren_token.span.span(),
-                        "column aliases are currently unsupported",
-                    ));
-                }
-
-                columns
-            },
+            columns: input
+                .peek(syn::token::Paren)
+                .then(|| input.parse::<ColumnList>())
+                .transpose()?,
         })
     }
 }
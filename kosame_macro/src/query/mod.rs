@@ -7,6 +7,8 @@ pub use field::*;
 pub use node::*;
 pub use node_path::*;
 
+use std::collections::HashMap;
+
 use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
 use syn::{
@@ -18,9 +20,11 @@ use crate::{
     attribute::{CustomMeta, MetaLocation},
     bind_params::{BindParamsBuilder, BindParamsClosure},
     correlations::{CorrelationId, Correlations},
+    dialect_check::DialectCheck,
     part::{Alias, TablePath},
     path_ext::PathExt,
     scopes::{ScopeId, Scopes},
+    stars::StarExpander,
 };
 
 pub struct Query {
@@ -31,11 +35,18 @@ pub struct Query {
     pub alias: Option<Alias>,
 }
 
+impl Query {
+    pub fn custom_meta(&self) -> CustomMeta {
+        CustomMeta::parse_attrs(&self._inner_attrs, MetaLocation::QueryInner)
+            .expect("custom meta should be checked during parsing")
+    }
+}
+
 impl Parse for Query {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         ScopeId::reset();
         CorrelationId::reset();
-        Ok(Self {
+        let mut query = Self {
             _inner_attrs: {
                 let attrs = Attribute::parse_inner(input)?;
                 CustomMeta::parse_attrs(&attrs, MetaLocation::QueryInner)?;
@@ -49,7 +60,13 @@ impl Parse for Query {
             table: input.parse()?,
             body: input.parse()?,
             alias: input.call(Alias::parse_optional)?,
-        })
+        };
+        // Queries have no table declarations injected, so only `*` of subqueries selecting from
+        // other subqueries, functions and values lists can be expanded.
+        query
+            .body
+            .expand_stars(&mut StarExpander::new(HashMap::new()))?;
+        Ok(query)
     }
 }
 
@@ -60,10 +77,13 @@ impl ToTokens for Query {
             None => &Ident::new("internal", Span::call_site()),
         };
 
+        let driver = self.custom_meta().driver();
+        self.body.accept(&mut DialectCheck::new(driver));
+
         let bind_params = {
             let mut builder = BindParamsBuilder::new();
             self.body.accept(&mut builder);
-            builder.build()
+            builder.build(driver)
         };
         let correlations = Correlations::from(self);
         let scopes = Scopes::from(self);
@@ -83,6 +103,17 @@ impl ToTokens for Query {
         };
 
         let lifetime = (!bind_params.is_empty()).then_some(quote! { <'a> });
+        let dialect = driver.dialect();
+        let exec_sql = bind_params.array_lengths().map(|array_lengths| {
+            quote! {
+                fn exec_sql(&self) -> ::std::result::Result<::std::borrow::Cow<'static, str>, ::kosame::sql::Error> {
+                    let array_lengths = #array_lengths;
+                    ::kosame::query::RecordArrayRunner {}
+                        .query_to_sql::<Self::Dialect>(self, &array_lengths)
+                        .map(::std::borrow::Cow::Owned)
+                }
+            }
+        });
 
         let module_tokens = quote! {
             pub mod #module_name {
@@ -101,9 +132,19 @@ impl ToTokens for Query {
                 impl #lifetime ::kosame::query::Query for Query #lifetime {
                     type Params = Params #lifetime;
                     type Row = Row;
+                    type Dialect = #dialect;
 
                     const REPR: ::kosame::query::Node<'static> = #query_node;
 
+                    fn sql(&self) -> ::std::result::Result<&'static str, ::kosame::sql::Error> {
+                        static SQL: ::std::sync::OnceLock<::std::result::Result<::std::string::String, ::kosame::sql::Error>> = ::std::sync::OnceLock::new();
+                        SQL.get_or_init(|| ::kosame::query::RecordArrayRunner {}.query_to_sql::<Self::Dialect>(self, &[]))
+                            .as_deref()
+                            .map_err(|error| *error)
+                    }
+
+                    #exec_sql
+
                     fn params(&self) -> &Self::Params {
                         &self.params
                     }
@@ -1,9 +1,11 @@
 use crate::clause::peek_clause;
 use crate::{
-    clause::{Limit, Offset, OrderBy, Where},
+    clause::{Distinct, Limit, Lock, Offset, OrderBy, Where},
+    command::Command,
     quote_option::QuoteOption,
     row::Row,
-    visitor::Visitor,
+    stars::StarExpander,
+    visitor::{SubqueryCollector, Visitor},
 };
 
 use super::star::Star;
@@ -20,19 +22,27 @@ pub struct Node {
     pub correlation_id: CorrelationId,
     pub scope_id: ScopeId,
     pub _brace: syn::token::Brace,
+    pub distinct: Option<Distinct>,
     pub star: Option<Star>,
     pub fields: Punctuated<Field, Token![,]>,
     pub r#where: Option<Where>,
     pub order_by: Option<OrderBy>,
     pub limit: Option<Limit>,
     pub offset: Option<Offset>,
+    pub lock: Option<Lock>,
 }
 
 impl Node {
     pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        if let Some(inner) = self.distinct.as_ref() {
+            inner.accept(visitor)
+        }
         for field in &self.fields {
             match field {
-                Field::Relation { node, .. } => node.accept(visitor),
+                Field::Relation { name, node, .. } => {
+                    visitor.visit_relation(name);
+                    node.accept(visitor);
+                }
                 Field::Expr { expr, .. } => expr.accept(visitor),
                 _ => {}
             }
@@ -50,6 +60,63 @@ impl Node {
         if let Some(inner) = self.offset.as_ref() {
             inner.accept(visitor)
         }
+        if let Some(inner) = self.lock.as_ref() {
+            inner.accept(visitor)
+        }
+    }
+
+    pub fn expand_stars(&mut self, stars: &mut StarExpander) -> syn::Result<()> {
+        if let Some(inner) = &mut self.distinct {
+            inner.expand_stars(stars)?;
+        }
+        for field in &mut self.fields {
+            match field {
+                Field::Relation { node, .. } => node.expand_stars(stars)?,
+                Field::Expr { expr, .. } => expr.expand_stars(stars)?,
+                _ => {}
+            }
+        }
+
+        if let Some(inner) = &mut self.r#where {
+            inner.expand_stars(stars)?;
+        }
+        if let Some(inner) = &mut self.order_by {
+            inner.expand_stars(stars)?;
+        }
+        if let Some(inner) = &mut self.limit {
+            inner.expand_stars(stars)?;
+        }
+        if let Some(inner) = &mut self.offset {
+            inner.expand_stars(stars)?;
+        }
+        Ok(())
+    }
+
+    /// Subqueries used as expressions in this node, e.g. in `exists (...)`. Subqueries of nested
+    /// relation nodes are not included.
+    pub fn expr_subqueries(&self) -> Vec<&Command> {
+        let mut collector = SubqueryCollector::new(0);
+        if let Some(inner) = self.distinct.as_ref() {
+            inner.accept(&mut collector)
+        }
+        for field in &self.fields {
+            if let Field::Expr { expr, .. } = field {
+                expr.accept(&mut collector);
+            }
+        }
+        if let Some(inner) = self.r#where.as_ref() {
+            inner.accept(&mut collector)
+        }
+        if let Some(inner) = self.order_by.as_ref() {
+            inner.accept(&mut collector)
+        }
+        if let Some(inner) = self.limit.as_ref() {
+            inner.accept(&mut collector)
+        }
+        if let Some(inner) = self.offset.as_ref() {
+            inner.accept(&mut collector)
+        }
+        collector.commands
     }
 
     pub fn to_row_tokens(
@@ -83,6 +150,7 @@ impl Node {
                             .map(|field| field.to_row_field(&table_path, node_path)),
                     )
                     .collect(),
+                query.custom_meta().driver(),
             )
         };
 
@@ -198,22 +266,26 @@ impl Node {
                 }
             }
 
+            let distinct = QuoteOption::from(&self.distinct);
             let star = self.star.is_some();
 
             let r#where = QuoteOption::from(&self.r#where);
             let order_by = QuoteOption::from(&self.order_by);
             let limit = QuoteOption::from(&self.limit);
             let offset = QuoteOption::from(&self.offset);
+            let lock = QuoteOption::from(&self.lock);
 
             quote! {
                 ::kosame::repr::query::Node::new(
                     &#table_path_call_site::TABLE,
+                    #distinct,
                     #star,
                     &[#(#fields),*],
                     #r#where,
                     #order_by,
                     #limit,
                     #offset,
+                    #lock,
                 )
             }
             .to_tokens(tokens);
@@ -226,6 +298,8 @@ impl Parse for Node {
         let content;
         let _brace = braced!(content in input);
 
+        let distinct = content.call(Distinct::parse_optional)?;
+
         let star = if content.fork().parse::<Star>().is_ok() {
             let star = Some(content.parse()?);
             if !content.is_empty() {
@@ -279,12 +353,14 @@ impl Parse for Node {
             correlation_id: CorrelationId::new(),
             scope_id: ScopeId::new(),
             _brace,
+            distinct,
             star,
             fields,
             r#where: content.call(Where::parse_optional)?,
             order_by: content.call(OrderBy::parse_optional)?,
             limit: content.call(Limit::parse_optional)?,
             offset: content.call(Offset::parse_optional)?,
+            lock: content.call(Lock::parse_optional)?,
         })
     }
 }
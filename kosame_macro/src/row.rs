@@ -2,18 +2,22 @@ use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 use syn::{Attribute, Ident};
 
+use crate::driver::Driver;
+
 pub struct Row {
     pub attrs: Vec<Attribute>,
     pub name: Ident,
     pub fields: Vec<RowField>,
+    pub driver: Driver,
 }
 
 impl Row {
-    pub fn new(attrs: Vec<Attribute>, name: Ident, fields: Vec<RowField>) -> Self {
+    pub fn new(attrs: Vec<Attribute>, name: Ident, fields: Vec<RowField>, driver: Driver) -> Self {
         Self {
             attrs,
             name,
             fields,
+            driver,
         }
     }
 }
@@ -23,6 +27,7 @@ impl ToTokens for Row {
         let attrs = &self.attrs;
         let name = &self.name;
         let fields = &self.fields;
+        let driver = self.driver.as_str();
 
         let derives = [
             quote! { ::kosame::Row },
@@ -35,6 +40,7 @@ impl ToTokens for Row {
 
         quote! {
             #[derive(#(#derives),*)]
+            #[kosame(driver = #driver)]
             #(#attrs)*
             pub struct #name {
                 #(#fields,)*
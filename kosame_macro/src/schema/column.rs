@@ -74,6 +74,12 @@ impl ToTokens for Column {
             };
 
         let not_null = self.constraints.not_null().is_some();
+        let nullability =
+            if self.constraints.not_null().is_none() && self.constraints.primary_key().is_none() {
+                quote! { ::kosame::infer::Nullable }
+            } else {
+                quote! { ::kosame::infer::NotNull }
+            };
         let primary_key = self.constraints.primary_key().is_some();
         let default = QuoteOption(self.constraints.default().map(|default| {
             let expr = default.expr();
@@ -93,6 +99,7 @@ impl ToTokens for Column {
                 pub type TypeNotNull = #rust_type_not_null;
                 pub type TypeNullable = #rust_type_nullable;
                 pub type Type = #rust_type_auto;
+                pub type Nullability = #nullability;
             }
         }
         .to_tokens(tokens);
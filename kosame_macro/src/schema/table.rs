@@ -39,6 +39,13 @@ pub struct Table {
     pub relations: Punctuated<Relation, Token![,]>,
 }
 
+impl Table {
+    pub fn custom_meta(&self) -> CustomMeta {
+        CustomMeta::parse_attrs(&self._inner_attrs, MetaLocation::TableInner)
+            .expect("custom meta should be checked during parsing")
+    }
+}
+
 impl Parse for Table {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let content;
@@ -97,6 +104,7 @@ impl ToTokens for Table {
                     RowField::new(vec![], column.clone(), quote! { columns::#column::Type })
                 })
                 .collect(),
+            self.custom_meta().driver(),
         );
 
         let star_macro = {
@@ -5,7 +5,7 @@ use quote::{ToTokens, format_ident, quote};
 use syn::Ident;
 
 use crate::{
-    clause::FromItem,
+    clause::{FromItem, OnConflict, Where},
     command::Command,
     correlations::CorrelationId,
     inferred_type::InferredType,
@@ -75,6 +75,16 @@ impl<'a> Scopes<'a> {
             .expect("scope ID must be valid");
         scope.infer_type(table, column)
     }
+
+    /// Whether the command of the scope has a `group by` clause. Without one, aggregates are
+    /// computed over a single group, which is empty if no rows match.
+    pub fn is_grouped(&self, scope_id: ScopeId) -> bool {
+        self.scopes
+            .iter()
+            .find(|scope| scope.id == scope_id)
+            .expect("scope ID must be valid")
+            .grouped
+    }
 }
 
 impl ToTokens for Scopes<'_> {
@@ -92,11 +102,19 @@ impl ToTokens for Scopes<'_> {
 struct Scope<'a> {
     id: ScopeId,
     items: Vec<ScopeItem<'a>>,
+    grouped: bool,
+    /// Qualified columns that are checked with `is not null` in the `where` clause.
+    not_null_columns: Vec<(&'a Ident, &'a Ident)>,
 }
 
 impl<'a> Scope<'a> {
     fn new(id: ScopeId, items: Vec<ScopeItem<'a>>) -> Self {
-        Self { id, items }
+        Self {
+            id,
+            items,
+            grouped: false,
+            not_null_columns: Vec::new(),
+        }
     }
 
     pub fn infer_type<'b>(
@@ -106,11 +124,21 @@ impl<'a> Scope<'a> {
     ) -> Option<InferredType<'b>> {
         let table = table?;
         let item = self.items.iter().find(|item| item.name() == Some(table))?;
-        Some(InferredType::Correlation {
+        let inferred_type = InferredType::Correlation {
             correlation_id: item.correlation_id(),
             column,
             nullable: item.nullable(),
-        })
+        };
+        if self
+            .not_null_columns
+            .iter()
+            .any(|(not_null_table, not_null_column)| {
+                *not_null_table == table && *not_null_column == column
+            })
+        {
+            return Some(InferredType::Narrowed(Box::new(inferred_type)));
+        }
+        Some(inferred_type)
     }
 }
 
@@ -130,7 +158,7 @@ impl ToTokens for Scope<'_> {
         let columns = self
             .items
             .iter()
-            .filter(|item| !item.is_inherited())
+            .filter(|item| !item.is_inherited() && !item.is_qualified_only())
             .filter_map(|item| item.name());
 
         quote! {
@@ -147,9 +175,11 @@ impl ToTokens for Scope<'_> {
     }
 }
 
+#[derive(Clone, Copy)]
 pub enum ScopeItem<'a> {
     TargetTable {
         target_table: &'a TargetTable,
+        inherited_from: Option<ScopeId>,
     },
     FromItem {
         from_item: &'a FromItem,
@@ -159,6 +189,10 @@ pub enum ScopeItem<'a> {
     QueryNode {
         node: &'a query::Node,
         name: &'a Ident,
+        inherited_from: Option<ScopeId>,
+    },
+    Excluded {
+        on_conflict: &'a OnConflict,
     },
 }
 
@@ -168,14 +202,16 @@ impl<'a> ScopeItem<'a> {
             Self::TargetTable { target_table, .. } => target_table.table.correlation_id,
             Self::FromItem { from_item, .. } => from_item.correlation_id(),
             Self::QueryNode { node, .. } => node.correlation_id,
+            Self::Excluded { on_conflict } => on_conflict.excluded_correlation_id,
         }
     }
 
-    pub fn name(&self) -> Option<&Ident> {
+    pub fn name(&self) -> Option<&'a Ident> {
         match self {
             Self::TargetTable { target_table, .. } => Some(target_table.name()),
             Self::FromItem { from_item, .. } => from_item.name(),
             Self::QueryNode { name, .. } => Some(name),
+            Self::Excluded { on_conflict } => Some(&on_conflict.excluded),
         }
     }
 
@@ -184,83 +220,145 @@ impl<'a> ScopeItem<'a> {
             Self::TargetTable { .. } => false,
             Self::FromItem { nullable, .. } => *nullable,
             Self::QueryNode { .. } => false,
+            Self::Excluded { .. } => false,
         }
     }
 
     pub fn is_inherited(&self) -> bool {
         match self {
-            Self::TargetTable { .. } => false,
+            Self::TargetTable { inherited_from, .. } => inherited_from.is_some(),
             Self::FromItem { inherited_from, .. } => inherited_from.is_some(),
-            Self::QueryNode { .. } => false,
+            Self::QueryNode { inherited_from, .. } => inherited_from.is_some(),
+            Self::Excluded { .. } => false,
+        }
+    }
+
+    /// Whether the columns of this item can only be referenced with the table name, e.g.
+    /// `excluded.title`, because they would otherwise shadow the columns of the target table.
+    pub fn is_qualified_only(&self) -> bool {
+        matches!(self, Self::Excluded { .. })
+    }
+
+    /// This item as it is visible from a subquery in the scope with the given ID.
+    fn inherited(self, scope_id: ScopeId) -> Self {
+        match self {
+            Self::TargetTable { target_table, .. } => Self::TargetTable {
+                target_table,
+                inherited_from: Some(scope_id),
+            },
+            // Columns of outer joined tables remain nullable in subqueries.
+            Self::FromItem {
+                from_item,
+                nullable,
+                ..
+            } => Self::FromItem {
+                from_item,
+                inherited_from: Some(scope_id),
+                nullable,
+            },
+            Self::QueryNode { node, name, .. } => Self::QueryNode {
+                node,
+                name,
+                inherited_from: Some(scope_id),
+            },
+            Self::Excluded { .. } => self,
         }
     }
 }
 
-impl<'a> From<&'a Command> for Scopes<'a> {
-    fn from(value: &'a Command) -> Self {
-        fn inner<'a>(
-            scopes: &mut Vec<Scope<'a>>,
-            command: &'a Command,
-            inherited_from_items: &mut Vec<(ScopeId, &'a FromItem)>,
-        ) {
-            let scope_id = command.scope_id;
-            let from_items_truncate = inherited_from_items.len();
-
-            let mut items = Vec::new();
-            let mut shadow = HashSet::new();
-
-            if let Some(with) = &command.with {
-                for with_item in &with.items {
-                    inner(scopes, &with_item.command, inherited_from_items);
-                }
-            }
+fn command_scopes<'a>(
+    scopes: &mut Vec<Scope<'a>>,
+    command: &'a Command,
+    inherited_items: &mut Vec<(ScopeId, ScopeItem<'a>)>,
+) {
+    let scope_id = command.scope_id;
+    let inherited_items_truncate = inherited_items.len();
 
-            if let Some(target_table) = command.target_table() {
-                shadow.insert(target_table.name());
-                items.push(ScopeItem::TargetTable { target_table });
-            }
+    let mut items = Vec::new();
+    let mut shadow = HashSet::new();
 
-            if let Some(from_chain) = command.from_chain() {
-                let nullables = from_chain.nullables();
+    if let Some(with) = &command.with {
+        for with_item in &with.items {
+            command_scopes(scopes, &with_item.command, inherited_items);
+        }
+    }
 
-                for (from_item, nullable) in from_chain.into_iter().zip(nullables.into_iter()) {
-                    inherited_from_items.push((scope_id, from_item));
+    // The `select` of `insert into ... select` cannot refer to the target table, except through
+    // its own `from` clause.
+    if let Some(command) = command.insert_select() {
+        command_scopes(scopes, command, inherited_items);
+    }
 
-                    if let Some(name) = from_item.name() {
-                        shadow.insert(name);
-                    }
+    if let Some(target_table) = command.target_table() {
+        shadow.insert(target_table.name());
+        let item = ScopeItem::TargetTable {
+            target_table,
+            inherited_from: None,
+        };
+        items.push(item);
+        inherited_items.push((scope_id, item));
+    }
 
-                    if let FromItem::Subquery { command, .. } = from_item {
-                        inner(scopes, command, inherited_from_items);
-                    }
+    if let Some(on_conflict) = command.on_conflict() {
+        items.push(ScopeItem::Excluded { on_conflict });
+    }
 
-                    items.push(ScopeItem::FromItem {
-                        from_item,
-                        inherited_from: None,
-                        nullable,
-                    });
-                }
+    if let Some(from_chain) = command.from_chain() {
+        let nullables = from_chain.nullables();
+
+        for (from_item, nullable) in from_chain.into_iter().zip(nullables) {
+            let item = ScopeItem::FromItem {
+                from_item,
+                inherited_from: None,
+                nullable,
+            };
+            inherited_items.push((scope_id, item));
+
+            if let Some(name) = from_item.name() {
+                shadow.insert(name);
             }
 
-            inherited_from_items.truncate(from_items_truncate);
-
-            for (inherited_from, from_item) in inherited_from_items.iter() {
-                if let Some(name) = from_item.name()
-                    && !shadow.contains(name)
-                {
-                    items.push(ScopeItem::FromItem {
-                        from_item,
-                        inherited_from: Some(*inherited_from),
-                        nullable: false,
-                    });
-                }
+            if let FromItem::Subquery { command, .. } = from_item {
+                command_scopes(scopes, command, inherited_items);
             }
 
-            scopes.push(Scope::new(scope_id, items));
+            items.push(item);
         }
+    }
+
+    // Subqueries in expressions may refer to all tables of this command.
+    for command in command.expr_subqueries() {
+        command_scopes(scopes, command, inherited_items);
+    }
 
+    inherited_items.truncate(inherited_items_truncate);
+
+    for command in command.set_operation_commands() {
+        command_scopes(scopes, command, inherited_items);
+    }
+
+    for (inherited_from, item) in inherited_items.iter() {
+        if let Some(name) = item.name()
+            && !shadow.contains(name)
+        {
+            items.push(item.inherited(*inherited_from));
+        }
+    }
+
+    scopes.push(Scope {
+        grouped: command.group_by().is_some(),
+        not_null_columns: command
+            .r#where()
+            .map(Where::not_null_columns)
+            .unwrap_or_default(),
+        ..Scope::new(scope_id, items)
+    });
+}
+
+impl<'a> From<&'a Command> for Scopes<'a> {
+    fn from(value: &'a Command) -> Self {
         let mut scopes = Vec::new();
-        inner(&mut scopes, value, &mut Vec::new());
+        command_scopes(&mut scopes, value, &mut Vec::new());
         scopes.sort_by_key(|v| v.id);
         Scopes { scopes }
     }
@@ -270,7 +368,15 @@ impl<'a> From<&'a Query> for Scopes<'a> {
     fn from(value: &'a Query) -> Self {
         fn inner<'a>(scopes: &mut Vec<Scope<'a>>, node: &'a query::Node, name: &'a Ident) {
             let scope_id = node.scope_id;
-            let items = vec![ScopeItem::QueryNode { node, name }];
+            let item = ScopeItem::QueryNode {
+                node,
+                name,
+                inherited_from: None,
+            };
+
+            for command in node.expr_subqueries() {
+                command_scopes(scopes, command, &mut vec![(scope_id, item)]);
+            }
 
             for field in node.fields.iter() {
                 if let query::Field::Relation { node, name, .. } = field {
@@ -278,7 +384,7 @@ impl<'a> From<&'a Query> for Scopes<'a> {
                 }
             }
 
-            scopes.push(Scope::new(scope_id, items));
+            scopes.push(Scope::new(scope_id, vec![item]));
         }
 
         let mut scopes = Vec::new();
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use syn::{Ident, Path};
+
+use crate::{
+    clause::FromItem,
+    part::{TableAlias, TargetTable},
+};
+
+/// Expands `*` and `table.*` in the field lists of a parsed statement into the columns they
+/// select, walking the statement like a [`Visitor`](crate::visitor::Visitor) but with mutable
+/// access.
+///
+/// The columns of schema tables are only known to the proc macro once the tables have been
+/// injected into the statement by their `inject!` macros. Tables that are missing are recorded, so
+/// that the statement can inject them and be expanded again.
+pub struct StarExpander {
+    tables: HashMap<Path, Vec<Ident>>,
+    missing_tables: Vec<Path>,
+    /// The with items visible to the command being expanded. The columns are `None` if they are
+    /// not known.
+    with_items: Vec<(Ident, Option<Vec<Ident>>)>,
+}
+
+impl StarExpander {
+    pub fn new(tables: HashMap<Path, Vec<Ident>>) -> Self {
+        Self {
+            tables,
+            missing_tables: Vec::new(),
+            with_items: Vec::new(),
+        }
+    }
+
+    pub fn into_missing_tables(self) -> Vec<Path> {
+        self.missing_tables
+    }
+
+    fn table_columns(&mut self, path: &Path) -> Option<Vec<Ident>> {
+        let columns = self.tables.get(path).cloned();
+        if columns.is_none() && !self.missing_tables.contains(path) {
+            self.missing_tables.push(path.clone());
+        }
+        columns
+    }
+
+    pub fn with_items_len(&self) -> usize {
+        self.with_items.len()
+    }
+
+    /// Makes a with item visible to the commands expanded after it.
+    pub fn push_with_item(&mut self, name: Ident, columns: Option<Vec<Ident>>) {
+        self.with_items.push((name, columns));
+    }
+
+    pub fn truncate_with_items(&mut self, len: usize) {
+        self.with_items.truncate(len);
+    }
+
+    fn with_item_columns(&self, name: &Ident) -> Option<Option<Vec<Ident>>> {
+        self.with_items
+            .iter()
+            .rev()
+            .find(|(with_item, _)| with_item == name)
+            .map(|(_, columns)| columns.clone())
+    }
+}
+
+/// A table that `*` or `table.*` may select the columns of.
+pub struct StarSource<'a> {
+    pub name: Option<&'a Ident>,
+    /// The columns of the table, or `None` if they are not known yet.
+    pub columns: Option<Vec<Ident>>,
+}
+
+impl<'a> StarSource<'a> {
+    pub fn from_item(from_item: &'a FromItem, stars: &mut StarExpander) -> Self {
+        let alias_columns = |alias: &Option<TableAlias>| {
+            alias
+                .as_ref()
+                .and_then(|alias| alias.columns.as_ref())
+                .map(|columns| columns.columns.iter().cloned().collect())
+        };
+        let columns = match from_item {
+            FromItem::Table {
+                table_path, alias, ..
+            } => alias_columns(alias).or_else(|| {
+                match table_path
+                    .get_ident()
+                    .and_then(|name| stars.with_item_columns(name))
+                {
+                    Some(columns) => columns,
+                    None => stars.table_columns(table_path.as_path()),
+                }
+            }),
+            FromItem::Subquery { command, alias, .. } => alias_columns(alias).or_else(|| {
+                command
+                    .fields()
+                    .filter(|fields| fields.is_expanded())
+                    .map(|fields| fields.columns().into_iter().cloned().collect())
+            }),
+            FromItem::Function { .. } | FromItem::Values { .. } => {
+                Some(from_item.columns(None).into_iter().cloned().collect())
+            }
+        };
+        Self {
+            name: from_item.name(),
+            columns,
+        }
+    }
+
+    pub fn target_table(target_table: &'a TargetTable, stars: &mut StarExpander) -> Self {
+        Self {
+            name: Some(target_table.name()),
+            columns: stars.table_columns(target_table.table.as_path()),
+        }
+    }
+}
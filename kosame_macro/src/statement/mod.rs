@@ -1,7 +1,7 @@
 use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
 use syn::{
-    Attribute, Ident, parenthesized,
+    Attribute, Ident, Path, parenthesized,
     parse::{Parse, ParseStream},
 };
 
@@ -10,21 +10,27 @@ use crate::{
     bind_params::{BindParamsBuilder, BindParamsClosure},
     command::Command,
     correlations::{CorrelationId, Correlations},
+    dialect_check::DialectCheck,
     part::Alias,
     row::Row,
     scopes::{ScopeId, Scopes},
+    stars::StarExpander,
     visitor::Visitor,
 };
 
 pub struct Statement {
+    _token_stream: TokenStream,
+
     pub _inner_attrs: Vec<Attribute>,
     pub _paren_token: Option<syn::token::Paren>,
     pub command: Command,
     pub alias: Option<Alias>,
+    /// The schema tables whose declarations are needed to expand `*` but have not been injected.
+    pub missing_tables: Vec<Path>,
 }
 
 impl Statement {
-    pub fn _custom_meta(&self) -> CustomMeta {
+    pub fn custom_meta(&self) -> CustomMeta {
         CustomMeta::parse_attrs(&self._inner_attrs, MetaLocation::StatementInner)
             .expect("custom meta should be checked during parsing")
     }
@@ -39,89 +45,83 @@ impl Parse for Statement {
         CorrelationId::reset();
         ScopeId::reset();
 
-        let inner_attrs = {
-            let attrs = input.call(Attribute::parse_inner)?;
-            CustomMeta::parse_attrs(&attrs, MetaLocation::StatementInner)?;
-            attrs
-        };
-        if input.peek(syn::token::Paren) {
+        let token_stream = input.fork().parse()?;
+        let inner_attrs = input.call(Attribute::parse_inner)?;
+        let custom_meta = CustomMeta::parse_attrs(&inner_attrs, MetaLocation::StatementInner)?;
+        let (_paren_token, mut command): (_, Command) = if input.peek(syn::token::Paren) {
             let content;
-            Ok(Self {
-                _inner_attrs: inner_attrs,
-                _paren_token: Some(parenthesized!(content in input)),
-                command: content.parse()?,
-                alias: input.call(Alias::parse_optional)?,
-            })
+            (Some(parenthesized!(content in input)), content.parse()?)
         } else {
-            Ok(Self {
-                _inner_attrs: inner_attrs,
-                _paren_token: None,
-                command: input.parse()?,
-                alias: input.call(Alias::parse_optional)?,
-            })
-        }
+            (None, input.parse()?)
+        };
+        let alias = input.call(Alias::parse_optional)?;
+
+        let mut stars = StarExpander::new(
+            custom_meta
+                .tables
+                .iter()
+                .map(|(path, table)| {
+                    (
+                        path.clone(),
+                        table
+                            .columns
+                            .iter()
+                            .map(|column| column.rust_name())
+                            .collect(),
+                    )
+                })
+                .collect(),
+        );
+        command.expand_stars(&mut stars)?;
+
+        Ok(Self {
+            _token_stream: token_stream,
+            _inner_attrs: inner_attrs,
+            _paren_token,
+            command,
+            alias,
+            missing_tables: stars.into_missing_tables(),
+        })
     }
 }
 
 impl ToTokens for Statement {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        // Prepass to get table schemas
-        // let custom_meta = self.custom_meta();
-
-        // fn collect_table_refs<'a>(statement: &'a Statement) -> HashSet<&'a Path> {
-        //     let mut table_refs = HashSet::<&'a Path>::new();
-        //     statement.accept(&mut |path: &'a Path| {
-        //         table_refs.insert(path);
-        //     });
-        //     table_refs
-        // }
-        // let table_refs = collect_table_refs(self);
-
-        // if custom_meta.pass == 0 {
-        //     let mut table_refs = TableRefs::new();
-        //     self.accept(&mut table_refs);
-        //     let table_refs = table_refs.build();
-        //     if !table_refs.is_empty() {
-        //         let token_stream = self.token_stream.clone();
-        //         let mut result = quote! {
-        //             (::kosame::statement!) {
-        //                 #![kosame(__pass = 1)]
-        //                 #token_stream
-        //             }
-        //         };
-        //
-        //         for (index, table_ref) in table_refs.iter().enumerate() {
-        //             if index == table_refs.len() - 1 {
-        //                 result = quote! {
-        //                     #table_ref::inject! {
-        //                         #result
-        //                         (#table_ref)
-        //                     }
-        //                 }
-        //             } else {
-        //                 result = quote! {
-        //                     (#table_ref::inject!) {
-        //                         #result
-        //                         (#table_ref)
-        //                     }
-        //                 }
-        //             }
-        //         }
-        //
-        //         result.to_tokens(tokens);
-        //         return;
-        //     }
-        // }
+        // `*` can only be expanded into the columns of schema tables once their declarations have
+        // been injected into the statement. The statement is expanded again with the declarations
+        // of all missing tables, each injected by the `inject!` macro of the table.
+        let missing_tables = &self.missing_tables;
+        if self.custom_meta().pass == 0 && !missing_tables.is_empty() {
+            let token_stream = &self._token_stream;
+            let mut result = quote! {
+                (::kosame::statement!) {
+                    #![kosame(__pass = 1)]
+                    #token_stream
+                }
+            };
+            for (index, table) in missing_tables.iter().enumerate() {
+                result = if index == missing_tables.len() - 1 {
+                    quote! { #table::inject! { #result (#table) } }
+                } else {
+                    quote! { (#table::inject!) { #result (#table) } }
+                };
+            }
+            result.to_tokens(tokens);
+            return;
+        }
 
         let module_name = match &self.alias {
             Some(alias) => &alias.ident,
             None => &Ident::new("internal", Span::call_site()),
         };
 
+        let driver = self.custom_meta().driver();
+        self.accept(&mut DialectCheck::new(driver));
+
         let bind_params = {
             let mut builder = BindParamsBuilder::new();
             self.accept(&mut builder);
-            builder.build()
+            builder.build(driver)
         };
         let correlations = Correlations::from(&self.command);
         let scopes = Scopes::from(&self.command);
@@ -139,6 +139,7 @@ impl ToTokens for Statement {
                             field.to_row_field(&correlations, &scopes, command.correlation_id)
                         })
                         .collect(),
+                    driver,
                 );
                 quote! { #row }
             }
@@ -146,6 +147,19 @@ impl ToTokens for Statement {
         };
 
         let lifetime = (!bind_params.is_empty()).then_some(quote! { <'a> });
+        let dialect = driver.dialect();
+        let exec_sql = bind_params.array_lengths().map(|array_lengths| {
+            quote! {
+                fn exec_sql(&self) -> ::std::result::Result<::std::borrow::Cow<'static, str>, ::kosame::sql::Error> {
+                    let array_lengths = #array_lengths;
+                    let mut sql = ::std::string::String::new();
+                    let mut formatter = ::kosame::sql::Formatter::<Self::Dialect>::new(&mut sql)
+                        .with_array_lengths(&array_lengths);
+                    ::kosame::sql::FmtSql::fmt_sql(&Self::REPR, &mut formatter)?;
+                    Ok(::std::borrow::Cow::Owned(sql))
+                }
+            }
+        });
 
         let module_tokens = quote! {
             pub mod #module_name {
@@ -162,9 +176,19 @@ impl ToTokens for Statement {
                 impl #lifetime ::kosame::statement::Statement for Statement #lifetime {
                     type Params = Params #lifetime;
                     type Row = Row;
+                    type Dialect = #dialect;
 
                     const REPR: ::kosame::repr::command::Command<'static> = #command;
 
+                    fn sql(&self) -> ::std::result::Result<&'static str, ::kosame::sql::Error> {
+                        static SQL: ::std::sync::OnceLock<::std::result::Result<::std::string::String, ::kosame::sql::Error>> = ::std::sync::OnceLock::new();
+                        SQL.get_or_init(|| ::kosame::sql::FmtSql::to_sql_string::<Self::Dialect>(&Self::REPR))
+                            .as_deref()
+                            .map_err(|error| *error)
+                    }
+
+                    #exec_sql
+
                     fn params(&self) -> &Self::Params {
                         &self.params
                     }
@@ -1,7 +1,64 @@
-use crate::{command::Command, expr::BindParam, part::TablePath};
+use syn::Ident;
+
+use crate::{
+    clause::{Distinct, Lock},
+    command::{Command, Insert},
+    expr::{Any, Binary, BindParam},
+    part::TablePath,
+};
 
 pub trait Visitor<'a> {
     fn visit_bind_param(&mut self, _bind_param: &'a BindParam) {}
+    /// Called instead of [`Visitor::visit_bind_param`] for bind parameters that hold an array,
+    /// e.g. in `= any(:ids)`.
+    fn visit_array_bind_param(&mut self, _bind_param: &'a BindParam) {}
     fn visit_table_path(&mut self, _table_path: &'a TablePath) {}
     fn visit_command(&mut self, _command: &'a Command) {}
+    fn leave_command(&mut self, _command: &'a Command) {}
+    fn visit_insert(&mut self, _insert: &'a Insert) {}
+    fn visit_any(&mut self, _any: &'a Any) {}
+    fn visit_binary(&mut self, _binary: &'a Binary) {}
+    fn visit_distinct(&mut self, _distinct: &'a Distinct) {}
+    fn visit_lock(&mut self, _lock: &'a Lock) {}
+    /// Called for the relation fields of query nodes, before the node of the relation is visited.
+    fn visit_relation(&mut self, _name: &'a Ident) {}
+    /// Called for subqueries used as expressions, e.g. in `exists (...)`, before the subquery's
+    /// command is visited.
+    fn visit_subquery(&mut self, _command: &'a Command) {}
+}
+
+/// Collects the expression subqueries of a command or query node, excluding those nested in other
+/// commands.
+pub struct SubqueryCollector<'a> {
+    depth: u32,
+    base_depth: u32,
+    pub commands: Vec<&'a Command>,
+}
+
+impl SubqueryCollector<'_> {
+    /// Use a base depth of 1 when visiting a command and 0 when visiting the parts of a query
+    /// node.
+    pub fn new(base_depth: u32) -> Self {
+        Self {
+            depth: 0,
+            base_depth,
+            commands: Vec::new(),
+        }
+    }
+}
+
+impl<'a> Visitor<'a> for SubqueryCollector<'a> {
+    fn visit_command(&mut self, _command: &'a Command) {
+        self.depth += 1;
+    }
+
+    fn leave_command(&mut self, _command: &'a Command) {
+        self.depth -= 1;
+    }
+
+    fn visit_subquery(&mut self, command: &'a Command) {
+        if self.depth == self.base_depth {
+            self.commands.push(command);
+        }
+    }
 }
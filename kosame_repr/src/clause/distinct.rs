@@ -0,0 +1,31 @@
+use std::fmt::Write;
+
+use crate::expr::Expr;
+
+pub enum Distinct<'a> {
+    /// `distinct`, which removes duplicate rows.
+    Rows,
+    /// `distinct on (...)`, which keeps only the first row of each set of rows where the given
+    /// expressions are equal. This is PostgreSQL-specific.
+    On(&'a [Expr<'a>]),
+}
+
+impl kosame_sql::FmtSql for Distinct<'_> {
+    fn fmt_sql<D>(&self, formatter: &mut kosame_sql::Formatter<D>) -> kosame_sql::Result
+    where
+        D: kosame_sql::Dialect,
+    {
+        formatter.write_str("distinct ")?;
+        if let Self::On(exprs) = self {
+            formatter.write_str("on (")?;
+            for (index, expr) in exprs.iter().enumerate() {
+                expr.fmt_sql(formatter)?;
+                if index != exprs.len() - 1 {
+                    formatter.write_str(", ")?;
+                }
+            }
+            formatter.write_str(") ")?;
+        }
+        Ok(())
+    }
+}
@@ -1,6 +1,11 @@
 use std::fmt::Write;
 
-use crate::{command::Command, expr::Expr, part::TableAlias};
+use crate::{
+    clause::Values,
+    command::Command,
+    expr::{Call, Expr},
+    part::TableAlias,
+};
 
 pub struct From<'a> {
     chain: FromChain<'a>,
@@ -78,6 +83,14 @@ pub enum FromItem<'a> {
         command: &'a Command<'a>,
         alias: Option<TableAlias<'a>>,
     },
+    Function {
+        call: &'a Call<'a>,
+        alias: Option<TableAlias<'a>>,
+    },
+    Values {
+        values: &'a Values<'a>,
+        alias: TableAlias<'a>,
+    },
 }
 
 impl kosame_sql::FmtSql for FromItem<'_> {
@@ -109,6 +122,24 @@ impl kosame_sql::FmtSql for FromItem<'_> {
                     alias.fmt_sql(formatter)?;
                 }
             }
+            Self::Function { call, alias } => {
+                call.fmt_sql(formatter)?;
+                if let Some(alias) = alias {
+                    formatter.write_str(" as ")?;
+                    alias.fmt_sql(formatter)?;
+                }
+            }
+            Self::Values { values, alias } => {
+                formatter.write_str("(values ")?;
+                for (index, row) in values.rows().iter().enumerate() {
+                    row.fmt_sql(formatter)?;
+                    if index != values.rows().len() - 1 {
+                        formatter.write_str(", ")?;
+                    }
+                }
+                formatter.write_str(") as ")?;
+                alias.fmt_sql(formatter)?;
+            }
         }
 
         Ok(())
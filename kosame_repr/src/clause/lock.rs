@@ -0,0 +1,72 @@
+use std::fmt::Write;
+
+pub struct Lock<'a> {
+    strength: LockStrength,
+    of: &'a [&'a str],
+    wait: Option<LockWait>,
+}
+
+impl<'a> Lock<'a> {
+    #[inline]
+    pub const fn new(strength: LockStrength, of: &'a [&'a str], wait: Option<LockWait>) -> Self {
+        Self { strength, of, wait }
+    }
+
+    #[inline]
+    pub const fn strength(&self) -> LockStrength {
+        self.strength
+    }
+
+    #[inline]
+    pub const fn of(&self) -> &'a [&'a str] {
+        self.of
+    }
+
+    #[inline]
+    pub const fn wait(&self) -> Option<LockWait> {
+        self.wait
+    }
+}
+
+impl kosame_sql::FmtSql for Lock<'_> {
+    fn fmt_sql<D>(&self, formatter: &mut kosame_sql::Formatter<D>) -> kosame_sql::Result
+    where
+        D: kosame_sql::Dialect,
+    {
+        match self.strength {
+            LockStrength::Update => formatter.write_str(" for update")?,
+            LockStrength::NoKeyUpdate => formatter.write_str(" for no key update")?,
+            LockStrength::Share => formatter.write_str(" for share")?,
+            LockStrength::KeyShare => formatter.write_str(" for key share")?,
+        }
+        if !self.of.is_empty() {
+            formatter.write_str(" of ")?;
+            for (index, table) in self.of.iter().enumerate() {
+                formatter.write_ident(table)?;
+                if index != self.of.len() - 1 {
+                    formatter.write_str(", ")?;
+                }
+            }
+        }
+        match self.wait {
+            Some(LockWait::Nowait) => formatter.write_str(" nowait")?,
+            Some(LockWait::SkipLocked) => formatter.write_str(" skip locked")?,
+            None => {}
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockStrength {
+    Update,
+    NoKeyUpdate,
+    Share,
+    KeyShare,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockWait {
+    Nowait,
+    SkipLocked,
+}
@@ -1,28 +1,37 @@
+mod distinct;
 mod field;
 mod from;
 mod group_by;
 mod having;
 mod limit;
+mod lock;
 mod offset;
+mod on_conflict;
 mod order_by;
 mod returning;
 mod select;
 mod set;
+mod set_operation;
 mod values;
 mod r#where;
+mod window;
 mod with;
 
+pub use distinct::*;
 pub use field::*;
 pub use from::*;
 pub use group_by::*;
 pub use having::*;
 pub use limit::*;
+pub use lock::*;
 pub use offset::*;
+pub use on_conflict::*;
 pub use order_by::*;
 pub use returning::*;
 pub use select::*;
 pub use set::*;
+pub use set_operation::*;
 pub use values::*;
 pub use r#where::*;
+pub use window::*;
 pub use with::*;
-
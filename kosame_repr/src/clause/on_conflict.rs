@@ -0,0 +1,89 @@
+use std::fmt::Write;
+
+use kosame_sql::UpsertSyntax;
+
+use crate::{
+    clause::{Set, Where},
+    part::ColumnList,
+};
+
+pub struct OnConflict<'a> {
+    target: Option<ColumnList<'a>>,
+    action: ConflictAction<'a>,
+}
+
+impl<'a> OnConflict<'a> {
+    #[inline]
+    pub const fn new(target: Option<ColumnList<'a>>, action: ConflictAction<'a>) -> Self {
+        Self { target, action }
+    }
+
+    #[inline]
+    pub const fn target(&self) -> Option<&ColumnList<'a>> {
+        self.target.as_ref()
+    }
+
+    #[inline]
+    pub const fn action(&self) -> &ConflictAction<'a> {
+        &self.action
+    }
+}
+
+impl kosame_sql::FmtSql for OnConflict<'_> {
+    fn fmt_sql<D>(&self, formatter: &mut kosame_sql::Formatter<D>) -> kosame_sql::Result
+    where
+        D: kosame_sql::Dialect,
+    {
+        match D::upsert_syntax() {
+            UpsertSyntax::OnConflict => {
+                formatter.write_str(" on conflict")?;
+                if let Some(target) = &self.target {
+                    target.fmt_sql(formatter)?;
+                }
+                match &self.action {
+                    ConflictAction::Nothing => formatter.write_str(" do nothing")?,
+                    ConflictAction::Update { set, r#where } => {
+                        formatter.write_str(" do update")?;
+                        set.fmt_sql(formatter)?;
+                        if let Some(r#where) = r#where {
+                            r#where.fmt_sql(formatter)?;
+                        }
+                    }
+                }
+            }
+            UpsertSyntax::OnDuplicateKeyUpdate => match &self.action {
+                // `on duplicate key update` handles conflicts on any unique key and cannot do
+                // nothing. `insert ignore` would also ignore errors other than conflicts. The
+                // macros reject conflict targets and `do nothing` for the `mysql` driver, so only
+                // hand-written representations end up here.
+                _ if self.target.is_some() => return Err(std::fmt::Error),
+                ConflictAction::Nothing => return Err(std::fmt::Error),
+                ConflictAction::Update { set, r#where } => {
+                    // `on duplicate key update` cannot be filtered. The macros reject this for
+                    // the `mysql` driver, so only hand-written representations end up here.
+                    if r#where.is_some() {
+                        return Err(std::fmt::Error);
+                    }
+                    formatter.write_str(" on duplicate key update ")?;
+                    for (index, item) in set.items().iter().enumerate() {
+                        item.fmt_sql(formatter)?;
+                        if index < set.items().len() - 1 {
+                            formatter.write_str(", ")?;
+                        }
+                    }
+                }
+            },
+            // None of the drivers supported by the macros use such a dialect.
+            UpsertSyntax::Unsupported => return Err(std::fmt::Error),
+        }
+        Ok(())
+    }
+}
+
+pub enum ConflictAction<'a> {
+    Nothing,
+    Update {
+        set: Set<'a>,
+        r#where: Option<Where<'a>>,
+    },
+}
@@ -11,6 +11,11 @@ impl<'a> OrderBy<'a> {
     pub const fn new(items: &'a [OrderByItem]) -> Self {
         Self { items }
     }
+
+    #[inline]
+    pub const fn items(&self) -> &'a [OrderByItem<'a>] {
+        self.items
+    }
 }
 
 impl kosame_sql::FmtSql for OrderBy<'_> {
@@ -1,15 +1,21 @@
 use std::fmt::Write;
 
-use crate::clause::Fields;
+use crate::clause::{Distinct, Fields};
 
 pub struct Select<'a> {
+    distinct: Option<Distinct<'a>>,
     fields: Fields<'a>,
 }
 
 impl<'a> Select<'a> {
     #[inline]
-    pub const fn new(fields: Fields<'a>) -> Self {
-        Self { fields }
+    pub const fn new(distinct: Option<Distinct<'a>>, fields: Fields<'a>) -> Self {
+        Self { distinct, fields }
+    }
+
+    #[inline]
+    pub const fn distinct(&self) -> Option<&Distinct<'a>> {
+        self.distinct.as_ref()
     }
 
     #[inline]
@@ -24,6 +30,9 @@ impl kosame_sql::FmtSql for Select<'_> {
         D: kosame_sql::Dialect,
     {
         formatter.write_str("select ")?;
+        if let Some(distinct) = &self.distinct {
+            distinct.fmt_sql(formatter)?;
+        }
         self.fields.fmt_sql(formatter)?;
         Ok(())
     }
@@ -0,0 +1,59 @@
+use std::fmt::Write;
+
+use crate::command::Command;
+
+pub struct SetOperation<'a> {
+    operator: SetOperator,
+    all: bool,
+    command: &'a Command<'a>,
+}
+
+impl<'a> SetOperation<'a> {
+    #[inline]
+    pub const fn new(operator: SetOperator, all: bool, command: &'a Command<'a>) -> Self {
+        Self {
+            operator,
+            all,
+            command,
+        }
+    }
+
+    #[inline]
+    pub const fn operator(&self) -> SetOperator {
+        self.operator
+    }
+
+    #[inline]
+    pub const fn all(&self) -> bool {
+        self.all
+    }
+
+    #[inline]
+    pub const fn command(&self) -> &'a Command<'a> {
+        self.command
+    }
+}
+
+impl kosame_sql::FmtSql for SetOperation<'_> {
+    fn fmt_sql<D>(&self, formatter: &mut kosame_sql::Formatter<D>) -> kosame_sql::Result
+    where
+        D: kosame_sql::Dialect,
+    {
+        match self.operator {
+            SetOperator::Union => formatter.write_str(" union ")?,
+            SetOperator::Intersect => formatter.write_str(" intersect ")?,
+            SetOperator::Except => formatter.write_str(" except ")?,
+        }
+        if self.all {
+            formatter.write_str("all ")?;
+        }
+        self.command.fmt_sql(formatter)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetOperator {
+    Union,
+    Intersect,
+    Except,
+}
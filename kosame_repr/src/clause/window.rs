@@ -0,0 +1,117 @@
+use std::fmt::Write;
+
+use crate::{clause::OrderBy, expr::Expr};
+
+pub struct Window<'a> {
+    items: &'a [WindowItem<'a>],
+}
+
+impl<'a> Window<'a> {
+    #[inline]
+    pub const fn new(items: &'a [WindowItem]) -> Self {
+        Self { items }
+    }
+}
+
+impl kosame_sql::FmtSql for Window<'_> {
+    #[inline]
+    fn fmt_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        formatter.write_str(" window ")?;
+        for (index, item) in self.items.iter().enumerate() {
+            item.fmt_sql(formatter)?;
+            if index != self.items.len() - 1 {
+                formatter.write_str(", ")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct WindowItem<'a> {
+    name: &'a str,
+    definition: WindowDefinition<'a>,
+}
+
+impl<'a> WindowItem<'a> {
+    #[inline]
+    pub const fn new(name: &'a str, definition: WindowDefinition<'a>) -> Self {
+        Self { name, definition }
+    }
+}
+
+impl kosame_sql::FmtSql for WindowItem<'_> {
+    #[inline]
+    fn fmt_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        formatter.write_ident(self.name)?;
+        formatter.write_str(" as ")?;
+        self.definition.fmt_sql(formatter)?;
+        Ok(())
+    }
+}
+
+/// The parenthesized definition of a window, as used in `over (...)` and `window w as (...)`.
+pub struct WindowDefinition<'a> {
+    existing: Option<&'a str>,
+    partition_by: &'a [Expr<'a>],
+    order_by: Option<OrderBy<'a>>,
+}
+
+impl<'a> WindowDefinition<'a> {
+    #[inline]
+    pub const fn new(
+        existing: Option<&'a str>,
+        partition_by: &'a [Expr<'a>],
+        order_by: Option<OrderBy<'a>>,
+    ) -> Self {
+        Self {
+            existing,
+            partition_by,
+            order_by,
+        }
+    }
+}
+
+impl kosame_sql::FmtSql for WindowDefinition<'_> {
+    #[inline]
+    fn fmt_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        formatter.write_str("(")?;
+        if let Some(existing) = self.existing {
+            formatter.write_ident(existing)?;
+        }
+        if !self.partition_by.is_empty() {
+            if self.existing.is_some() {
+                formatter.write_str(" ")?;
+            }
+            formatter.write_str("partition by ")?;
+            for (index, expr) in self.partition_by.iter().enumerate() {
+                expr.fmt_sql(formatter)?;
+                if index != self.partition_by.len() - 1 {
+                    formatter.write_str(", ")?;
+                }
+            }
+        }
+        if let Some(order_by) = &self.order_by {
+            if self.existing.is_some() || !self.partition_by.is_empty() {
+                formatter.write_str(" ")?;
+            }
+            formatter.write_str("order by ")?;
+            for (index, item) in order_by.items().iter().enumerate() {
+                item.fmt_sql(formatter)?;
+                if index != order_by.items().len() - 1 {
+                    formatter.write_str(", ")?;
+                }
+            }
+        }
+        formatter.write_str(")")?;
+        Ok(())
+    }
+}
@@ -3,13 +3,14 @@ use std::fmt::Write;
 use crate::{command::Command, part::TableAlias};
 
 pub struct With<'a> {
+    recursive: bool,
     items: &'a [WithItem<'a>],
 }
 
 impl<'a> With<'a> {
     #[inline]
-    pub const fn new(items: &'a [WithItem]) -> Self {
-        Self { items }
+    pub const fn new(recursive: bool, items: &'a [WithItem]) -> Self {
+        Self { recursive, items }
     }
 }
 
@@ -20,6 +21,9 @@ impl kosame_sql::FmtSql for With<'_> {
         formatter: &mut kosame_sql::Formatter<D>,
     ) -> kosame_sql::Result {
         formatter.write_str("with ")?;
+        if self.recursive {
+            formatter.write_str("recursive ")?;
+        }
         for (index, item) in self.items.iter().enumerate() {
             item.fmt_sql(formatter)?;
             if index != self.items.len() - 1 {
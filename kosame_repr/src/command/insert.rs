@@ -1,10 +1,18 @@
 use std::fmt::Write;
 
-use crate::{clause::*, part::TargetTable};
+use kosame_sql::UpsertSyntax;
+
+use crate::{
+    clause::*,
+    command::Command,
+    part::{ColumnList, TargetTable},
+};
 
 pub struct Insert<'a> {
     target_table: TargetTable<'a>,
-    values: Values<'a>,
+    columns: Option<ColumnList<'a>>,
+    source: InsertSource<'a>,
+    on_conflict: Option<OnConflict<'a>>,
     returning: Option<Returning<'a>>,
 }
 
@@ -12,12 +20,16 @@ impl<'a> Insert<'a> {
     #[inline]
     pub const fn new(
         target_table: TargetTable<'a>,
-        values: Values<'a>,
+        columns: Option<ColumnList<'a>>,
+        source: InsertSource<'a>,
+        on_conflict: Option<OnConflict<'a>>,
         returning: Option<Returning<'a>>,
     ) -> Self {
         Self {
             target_table,
-            values,
+            columns,
+            source,
+            on_conflict,
             returning,
         }
     }
@@ -28,8 +40,18 @@ impl<'a> Insert<'a> {
     }
 
     #[inline]
-    pub const fn values(&self) -> &Values<'a> {
-        &self.values
+    pub const fn columns(&self) -> Option<&ColumnList<'a>> {
+        self.columns.as_ref()
+    }
+
+    #[inline]
+    pub const fn source(&self) -> &InsertSource<'a> {
+        &self.source
+    }
+
+    #[inline]
+    pub const fn on_conflict(&self) -> Option<&OnConflict<'a>> {
+        self.on_conflict.as_ref()
     }
 
     #[inline]
@@ -43,10 +65,30 @@ impl kosame_sql::FmtSql for Insert<'_> {
     where
         D: kosame_sql::Dialect,
     {
+        let on_duplicate_key_update = D::upsert_syntax() == UpsertSyntax::OnDuplicateKeyUpdate;
+
         formatter.write_str("insert into ")?;
         self.target_table.fmt_sql(formatter)?;
 
-        self.values.fmt_sql(formatter)?;
+        if let Some(columns) = &self.columns {
+            columns.fmt_sql(formatter)?;
+        }
+
+        self.source.fmt_sql(formatter)?;
+
+        if let Some(on_conflict) = &self.on_conflict {
+            // Makes the inserted row available as `excluded`, like in PostgreSQL.
+            if on_duplicate_key_update && let ConflictAction::Update { .. } = on_conflict.action() {
+                if let InsertSource::Select(..) = self.source {
+                    // MySQL only supports row aliases for `values` lists. The macros reject
+                    // this for the `mysql` driver.
+                    return Err(std::fmt::Error);
+                }
+                formatter.write_str(" as ")?;
+                formatter.write_ident("excluded")?;
+            }
+            on_conflict.fmt_sql(formatter)?;
+        }
 
         if let Some(returning) = &self.returning {
             returning.fmt_sql(formatter)?;
@@ -55,3 +97,26 @@ impl kosame_sql::FmtSql for Insert<'_> {
         Ok(())
     }
 }
+
+pub enum InsertSource<'a> {
+    Values(Values<'a>),
+    Select(&'a Command<'a>),
+    DefaultValues,
+}
+
+impl kosame_sql::FmtSql for InsertSource<'_> {
+    fn fmt_sql<D>(&self, formatter: &mut kosame_sql::Formatter<D>) -> kosame_sql::Result
+    where
+        D: kosame_sql::Dialect,
+    {
+        match self {
+            Self::Values(values) => values.fmt_sql(formatter)?,
+            Self::Select(command) => {
+                formatter.write_str(" ")?;
+                command.fmt_sql(formatter)?;
+            }
+            Self::DefaultValues => D::fmt_default_values(formatter)?,
+        }
+        Ok(())
+    }
+}
@@ -34,6 +34,8 @@ impl kosame_sql::FmtSql for Command<'_> {
     }
 }
 
+// Commands are only constructed in constants, where boxing the larger variants is not possible.
+#[allow(clippy::large_enum_variant)]
 pub enum CommandType<'a> {
     Delete(Delete<'a>),
     Insert(Insert<'a>),
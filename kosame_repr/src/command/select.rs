@@ -6,9 +6,12 @@ pub struct Select<'a> {
     r#where: Option<Where<'a>>,
     group_by: Option<GroupBy<'a>>,
     having: Option<Having<'a>>,
+    window: Option<Window<'a>>,
+    set_operations: &'a [SetOperation<'a>],
     order_by: Option<OrderBy<'a>>,
     limit: Option<Limit<'a>>,
     offset: Option<Offset<'a>>,
+    lock: Option<Lock<'a>>,
 }
 
 impl<'a> Select<'a> {
@@ -20,9 +23,12 @@ impl<'a> Select<'a> {
         r#where: Option<Where<'a>>,
         group_by: Option<GroupBy<'a>>,
         having: Option<Having<'a>>,
+        window: Option<Window<'a>>,
+        set_operations: &'a [SetOperation<'a>],
         order_by: Option<OrderBy<'a>>,
         limit: Option<Limit<'a>>,
         offset: Option<Offset<'a>>,
+        lock: Option<Lock<'a>>,
     ) -> Self {
         Self {
             select,
@@ -30,9 +36,12 @@ impl<'a> Select<'a> {
             r#where,
             group_by,
             having,
+            window,
+            set_operations,
             order_by,
             limit,
             offset,
+            lock,
         }
     }
 
@@ -61,6 +70,16 @@ impl<'a> Select<'a> {
         self.having.as_ref()
     }
 
+    #[inline]
+    pub const fn window(&self) -> Option<&Window<'a>> {
+        self.window.as_ref()
+    }
+
+    #[inline]
+    pub const fn set_operations(&self) -> &'a [SetOperation<'a>] {
+        self.set_operations
+    }
+
     #[inline]
     pub const fn order_by(&self) -> Option<&OrderBy<'a>> {
         self.order_by.as_ref()
@@ -75,6 +94,11 @@ impl<'a> Select<'a> {
     pub const fn offset(&self) -> Option<&Offset<'a>> {
         self.offset.as_ref()
     }
+
+    #[inline]
+    pub const fn lock(&self) -> Option<&Lock<'a>> {
+        self.lock.as_ref()
+    }
 }
 
 impl kosame_sql::FmtSql for Select<'_> {
@@ -95,6 +119,12 @@ impl kosame_sql::FmtSql for Select<'_> {
         if let Some(inner) = self.having.as_ref() {
             inner.fmt_sql(formatter)?;
         }
+        if let Some(inner) = self.window.as_ref() {
+            inner.fmt_sql(formatter)?;
+        }
+        for inner in self.set_operations {
+            inner.fmt_sql(formatter)?;
+        }
         if let Some(inner) = self.order_by.as_ref() {
             inner.fmt_sql(formatter)?;
         }
@@ -104,6 +134,9 @@ impl kosame_sql::FmtSql for Select<'_> {
         if let Some(inner) = self.offset.as_ref() {
             inner.fmt_sql(formatter)?;
         }
+        if let Some(inner) = self.lock.as_ref() {
+            inner.fmt_sql(formatter)?;
+        }
 
         Ok(())
     }
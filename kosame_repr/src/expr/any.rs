@@ -0,0 +1,51 @@
+use std::fmt::Write;
+
+use super::Expr;
+
+/// `lhs = any(rhs)`, where `rhs` is an array.
+pub struct Any<'a> {
+    lhs: &'a Expr<'a>,
+    rhs: &'a Expr<'a>,
+}
+
+impl<'a> Any<'a> {
+    #[inline]
+    pub const fn new(lhs: &'a Expr<'a>, rhs: &'a Expr<'a>) -> Self {
+        Self { lhs, rhs }
+    }
+}
+
+impl kosame_sql::FmtSql for Any<'_> {
+    fn fmt_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        if D::supports_arrays() {
+            self.lhs.fmt_sql(formatter)?;
+            formatter.write_str(" = any(")?;
+            self.rhs.fmt_sql(formatter)?;
+            formatter.write_str(")")?;
+            return Ok(());
+        }
+
+        // Without array support, only bind parameters can be expanded into an `in` list. The
+        // macros reject other arrays for the drivers of such dialects.
+        let Expr::BindParam(bind_param) = self.rhs else {
+            return Err(std::fmt::Error);
+        };
+        if formatter.array_len(bind_param.name()) == Some(0) {
+            // `in ()` is not valid SQL. Like `= any` of an empty array, the predicate must be false
+            // even if `lhs` is `null`, so that negating it is true. `lhs` is still written, since
+            // it may contain positional bind parameters.
+            formatter.write_str("(")?;
+            self.lhs.fmt_sql(formatter)?;
+            formatter.write_str(" is null and 1 = 0)")?;
+            return Ok(());
+        }
+        self.lhs.fmt_sql(formatter)?;
+        formatter.write_str(" in (")?;
+        bind_param.fmt_array_sql(formatter)?;
+        formatter.write_str(")")?;
+        Ok(())
+    }
+}
@@ -21,6 +21,16 @@ impl kosame_sql::FmtSql for Binary<'_> {
         &self,
         formatter: &mut kosame_sql::Formatter<D>,
     ) -> std::fmt::Result {
+        if let BinOp::Concat = self.op
+            && !D::supports_concat_operator()
+        {
+            formatter.write_str("concat(")?;
+            self.left.fmt_sql(formatter)?;
+            formatter.write_str(", ")?;
+            self.right.fmt_sql(formatter)?;
+            formatter.write_str(")")?;
+            return Ok(());
+        }
         self.left.fmt_sql(formatter)?;
         self.op.fmt_sql(formatter)?;
         self.right.fmt_sql(formatter)?;
@@ -36,6 +46,13 @@ pub enum BinOp {
     // addition, subtraction
     Add,
     Subtract,
+    // string concatenation
+    Concat,
+    // pattern matching
+    Like,
+    NotLike,
+    ILike,
+    NotILike,
     // comparison operators
     Eq,
     Uneq,
@@ -64,6 +81,11 @@ impl kosame_sql::FmtSql for BinOp {
             Self::Modulo => formatter.write_str(" % "),
             Self::Add => formatter.write_str(" + "),
             Self::Subtract => formatter.write_str(" - "),
+            Self::Concat => formatter.write_str(" || "),
+            Self::Like => formatter.write_str(" like "),
+            Self::NotLike => formatter.write_str(" not like "),
+            Self::ILike => formatter.write_str(" ilike "),
+            Self::NotILike => formatter.write_str(" not ilike "),
             Self::Eq => formatter.write_str(" = "),
             Self::Uneq => formatter.write_str(" <> "),
             Self::LessThan => formatter.write_str(" < "),
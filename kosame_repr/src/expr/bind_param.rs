@@ -8,6 +8,20 @@ impl<'a> BindParam<'a> {
     pub const fn new(name: &'a str, ordinal: u32) -> Self {
         Self { name, ordinal }
     }
+
+    #[inline]
+    pub const fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Formats this bind parameter as an array, see [`kosame_sql::Formatter::write_array_bind_param`].
+    #[inline]
+    pub fn fmt_array_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        formatter.write_array_bind_param(self.name, self.ordinal)
+    }
 }
 
 impl kosame_sql::FmtSql for BindParam<'_> {
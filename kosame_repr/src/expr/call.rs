@@ -1,20 +1,28 @@
 use std::fmt::Write;
 
 use super::Expr;
+use crate::clause::WindowDefinition;
 
 pub struct Call<'a> {
     function: &'a str,
     params: &'a [&'a Expr<'a>],
     keyword: bool,
+    aggregate: Option<&'a Aggregate<'a>>,
 }
 
 impl<'a> Call<'a> {
     #[inline]
-    pub const fn new(function: &'a str, params: &'a [&'a Expr], keyword: bool) -> Self {
+    pub const fn new(
+        function: &'a str,
+        params: &'a [&'a Expr],
+        keyword: bool,
+        aggregate: Option<&'a Aggregate<'a>>,
+    ) -> Self {
         Self {
             function,
             params,
             keyword,
+            aggregate,
         }
     }
 }
@@ -41,6 +49,57 @@ impl kosame_sql::FmtSql for Call<'_> {
             }
         }
         formatter.write_str(")")?;
+
+        if let Some(aggregate) = self.aggregate {
+            aggregate.fmt_sql(formatter)?;
+        }
+        Ok(())
+    }
+}
+
+/// The `filter (where ...)` and `over ...` clauses of an aggregate or window function call.
+pub struct Aggregate<'a> {
+    filter: Option<&'a Expr<'a>>,
+    over: Option<Over<'a>>,
+}
+
+impl<'a> Aggregate<'a> {
+    #[inline]
+    pub const fn new(filter: Option<&'a Expr<'a>>, over: Option<Over<'a>>) -> Self {
+        Self { filter, over }
+    }
+}
+
+impl kosame_sql::FmtSql for Aggregate<'_> {
+    #[inline]
+    fn fmt_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        if let Some(filter) = self.filter {
+            formatter.write_str(" filter (where ")?;
+            filter.fmt_sql(formatter)?;
+            formatter.write_str(")")?;
+        }
+
+        match &self.over {
+            Some(Over::Window(name)) => {
+                formatter.write_str(" over ")?;
+                formatter.write_ident(name)?;
+            }
+            Some(Over::Definition(definition)) => {
+                formatter.write_str(" over ")?;
+                definition.fmt_sql(formatter)?;
+            }
+            None => {}
+        }
         Ok(())
     }
 }
+
+/// The window a window function is computed over.
+pub enum Over<'a> {
+    /// A window defined in the `window` clause of the `select`.
+    Window(&'a str),
+    Definition(WindowDefinition<'a>),
+}
@@ -0,0 +1,63 @@
+use std::fmt::Write;
+
+use super::Expr;
+
+pub struct Case<'a> {
+    operand: Option<&'a Expr<'a>>,
+    branches: &'a [CaseBranch<'a>],
+    r#else: Option<&'a Expr<'a>>,
+}
+
+impl<'a> Case<'a> {
+    #[inline]
+    pub const fn new(
+        operand: Option<&'a Expr<'a>>,
+        branches: &'a [CaseBranch<'a>],
+        r#else: Option<&'a Expr<'a>>,
+    ) -> Self {
+        Self {
+            operand,
+            branches,
+            r#else,
+        }
+    }
+}
+
+impl kosame_sql::FmtSql for Case<'_> {
+    fn fmt_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        formatter.write_str("case ")?;
+        if let Some(operand) = self.operand {
+            operand.fmt_sql(formatter)?;
+            formatter.write_str(" ")?;
+        }
+        for branch in self.branches {
+            formatter.write_str("when ")?;
+            branch.condition.fmt_sql(formatter)?;
+            formatter.write_str(" then ")?;
+            branch.result.fmt_sql(formatter)?;
+            formatter.write_str(" ")?;
+        }
+        if let Some(r#else) = self.r#else {
+            formatter.write_str("else ")?;
+            r#else.fmt_sql(formatter)?;
+            formatter.write_str(" ")?;
+        }
+        formatter.write_str("end")?;
+        Ok(())
+    }
+}
+
+pub struct CaseBranch<'a> {
+    condition: Expr<'a>,
+    result: Expr<'a>,
+}
+
+impl<'a> CaseBranch<'a> {
+    #[inline]
+    pub const fn new(condition: Expr<'a>, result: Expr<'a>) -> Self {
+        Self { condition, result }
+    }
+}
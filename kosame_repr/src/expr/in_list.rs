@@ -0,0 +1,49 @@
+use std::fmt::Write;
+
+use crate::command::Command;
+
+use super::Expr;
+
+pub struct InList<'a> {
+    lhs: &'a Expr<'a>,
+    not: bool,
+    items: InListItems<'a>,
+}
+
+impl<'a> InList<'a> {
+    #[inline]
+    pub const fn new(lhs: &'a Expr<'a>, not: bool, items: InListItems<'a>) -> Self {
+        Self { lhs, not, items }
+    }
+}
+
+impl kosame_sql::FmtSql for InList<'_> {
+    fn fmt_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        self.lhs.fmt_sql(formatter)?;
+        if self.not {
+            formatter.write_str(" not")?;
+        }
+        formatter.write_str(" in (")?;
+        match self.items {
+            InListItems::Exprs(exprs) => {
+                for (index, expr) in exprs.iter().enumerate() {
+                    expr.fmt_sql(formatter)?;
+                    if index != exprs.len() - 1 {
+                        formatter.write_str(", ")?;
+                    }
+                }
+            }
+            InListItems::Subquery(command) => command.fmt_sql(formatter)?,
+        }
+        formatter.write_str(")")?;
+        Ok(())
+    }
+}
+
+pub enum InListItems<'a> {
+    Exprs(&'a [Expr<'a>]),
+    Subquery(&'a Command<'a>),
+}
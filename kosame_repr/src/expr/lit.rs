@@ -16,7 +16,8 @@ impl kosame_sql::FmtSql for Lit {
     ) -> kosame_sql::Result {
         match self {
             Self::Int(inner) => write!(formatter, "{}", inner),
-            Self::Float(inner) => write!(formatter, "{}", inner),
+            // `Debug` keeps the fraction of whole numbers, so that `2.0` is not sent as an integer.
+            Self::Float(inner) => write!(formatter, "{:?}", inner),
             Self::Str(inner) => write!(formatter, "'{}'", inner.replace("'", "''")),
             Self::Bool(inner) => write!(formatter, "{}", inner),
             Self::Null => formatter.write_str("null"),
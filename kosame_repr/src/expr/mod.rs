@@ -1,46 +1,70 @@
+mod any;
+mod between;
 mod binary;
 mod bind_param;
 mod call;
+mod case;
 mod cast;
 mod column_ref;
+mod exists;
+mod in_list;
 mod lit;
 mod paren;
 mod raw;
+mod subquery;
 mod unary;
 
+pub use any::*;
+pub use between::*;
 pub use binary::*;
 pub use bind_param::*;
 pub use call::*;
+pub use case::*;
 pub use cast::*;
 pub use column_ref::*;
+pub use exists::*;
+pub use in_list::*;
 pub use lit::*;
 pub use paren::*;
 pub use raw::*;
+pub use subquery::*;
 pub use unary::*;
 
 pub enum Expr<'a> {
+    Any(Any<'a>),
+    Between(Between<'a>),
     Binary(Binary<'a>),
     BindParam(BindParam<'a>),
     Call(Call<'a>),
+    Case(Case<'a>),
     Cast(Cast<'a>),
     ColumnRef(ColumnRef<'a>),
+    Exists(Exists<'a>),
+    InList(InList<'a>),
     Lit(Lit),
     Paren(Paren<'a>),
     Raw(Raw<'a>),
+    Subquery(Subquery<'a>),
     Unary(Unary<'a>),
 }
 
 macro_rules! variants {
     ($macro:ident!()) => {
         $macro!(
+            Any
+            Between
             Binary
             BindParam
             Call
+            Case
             Cast
             ColumnRef
+            Exists
+            InList
             Lit
             Paren
             Raw
+            Subquery
             Unary
         )
     };
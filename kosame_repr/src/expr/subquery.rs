@@ -0,0 +1,26 @@
+use std::fmt::Write;
+
+use crate::command::Command;
+
+pub struct Subquery<'a> {
+    command: &'a Command<'a>,
+}
+
+impl<'a> Subquery<'a> {
+    #[inline]
+    pub const fn new(command: &'a Command<'a>) -> Self {
+        Self { command }
+    }
+}
+
+impl kosame_sql::FmtSql for Subquery<'_> {
+    fn fmt_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        formatter.write_str("(")?;
+        self.command.fmt_sql(formatter)?;
+        formatter.write_str(")")?;
+        Ok(())
+    }
+}
@@ -4,32 +4,39 @@ use super::Field;
 
 pub struct Node<'a> {
     table: &'a Table<'a>,
+    distinct: Option<Distinct<'a>>,
     star: bool,
     fields: &'a [Field<'a>],
     r#where: Option<Where<'a>>,
     order_by: Option<OrderBy<'a>>,
     limit: Option<Limit<'a>>,
     offset: Option<Offset<'a>>,
+    lock: Option<Lock<'a>>,
 }
 
 impl<'a> Node<'a> {
+    #[allow(clippy::too_many_arguments)]
     pub const fn new(
         table: &'a Table<'a>,
+        distinct: Option<Distinct<'a>>,
         star: bool,
         fields: &'a [Field<'a>],
         r#where: Option<Where<'a>>,
         order_by: Option<OrderBy<'a>>,
         limit: Option<Limit<'a>>,
         offset: Option<Offset<'a>>,
+        lock: Option<Lock<'a>>,
     ) -> Self {
         Self {
             table,
+            distinct,
             star,
             fields,
             r#where,
             order_by,
             limit,
             offset,
+            lock,
         }
     }
 
@@ -38,6 +45,11 @@ impl<'a> Node<'a> {
         self.table
     }
 
+    #[inline]
+    pub const fn distinct(&self) -> Option<&Distinct<'_>> {
+        self.distinct.as_ref()
+    }
+
     #[inline]
     pub const fn star(&self) -> bool {
         self.star
@@ -67,4 +79,9 @@ impl<'a> Node<'a> {
     pub const fn offset(&self) -> Option<&Offset<'_>> {
         self.offset.as_ref()
     }
+
+    #[inline]
+    pub const fn lock(&self) -> Option<&Lock<'_>> {
+        self.lock.as_ref()
+    }
 }
@@ -3,4 +3,32 @@ use std::fmt::Write;
 pub trait Dialect {
     fn ident_esc() -> (&'static str, &'static str);
     fn fmt_bind_param(formatter: &mut impl Write, name: &str, ordinal: u32) -> std::fmt::Result;
+    fn upsert_syntax() -> UpsertSyntax;
+
+    /// Whether arrays can be bound as a single parameter. If not, array bind parameters are
+    /// expanded into a list of bind parameters, one for each element.
+    fn supports_arrays() -> bool {
+        false
+    }
+
+    /// Whether strings are concatenated with the `||` operator. If not, `concat(...)` is used
+    /// instead.
+    fn supports_concat_operator() -> bool {
+        true
+    }
+
+    /// Writes the source of an `insert` statement that inserts a single row of default values.
+    fn fmt_default_values(formatter: &mut impl Write) -> std::fmt::Result {
+        formatter.write_str(" default values")
+    }
+}
+
+/// The syntax a dialect uses for `insert` statements that update or skip conflicting rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpsertSyntax {
+    /// `on conflict (...) do nothing` and `on conflict (...) do update set ...`.
+    OnConflict,
+    /// `on duplicate key update ...`, with the inserted row aliased as `excluded`.
+    OnDuplicateKeyUpdate,
+    Unsupported,
 }
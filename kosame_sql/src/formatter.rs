@@ -4,6 +4,7 @@ use crate::Dialect;
 
 pub struct Formatter<'a, D> {
     buf: &'a mut (dyn Write + 'a),
+    array_lengths: &'a [(&'a str, usize)],
     _dialect: std::marker::PhantomData<D>,
 }
 
@@ -14,10 +15,18 @@ where
     pub fn new(buf: &'a mut (dyn Write + 'a)) -> Self {
         Self {
             buf,
+            array_lengths: &[],
             _dialect: Default::default(),
         }
     }
 
+    /// Sets the number of elements of each array bind parameter by name. These are required to
+    /// expand array bind parameters in dialects without array support.
+    pub fn with_array_lengths(mut self, array_lengths: &'a [(&'a str, usize)]) -> Self {
+        self.array_lengths = array_lengths;
+        self
+    }
+
     pub fn write_ident(&mut self, ident: &str) -> crate::Result {
         let (prefix, suffix) = D::ident_esc();
         write!(self, "{prefix}{ident}{suffix}")
@@ -26,6 +35,36 @@ where
     pub fn write_bind_param(&mut self, name: &str, ordinal: u32) -> crate::Result {
         D::fmt_bind_param(self, name, ordinal)
     }
+
+    /// The number of elements of an array bind parameter, if known. See
+    /// [`Formatter::with_array_lengths`].
+    pub fn array_len(&self, name: &str) -> Option<usize> {
+        self.array_lengths
+            .iter()
+            .find(|(array_name, _)| *array_name == name)
+            .map(|&(_, len)| len)
+    }
+
+    /// Writes a bind parameter that holds an array. In dialects without array support, this is a
+    /// comma-separated list of one bind parameter per element, which is empty if the array is.
+    /// If the length of the array is unknown, a single bind parameter is written instead.
+    pub fn write_array_bind_param(&mut self, name: &str, ordinal: u32) -> crate::Result {
+        if D::supports_arrays() {
+            return self.write_bind_param(name, ordinal);
+        }
+
+        let Some(len) = self.array_len(name) else {
+            return self.write_bind_param(name, ordinal);
+        };
+
+        for index in 0..len {
+            if index != 0 {
+                self.write_str(", ")?;
+            }
+            D::fmt_bind_param(self, &array_element_name(name, index), ordinal)?;
+        }
+        Ok(())
+    }
 }
 
 impl<'a, D> Write for Formatter<'a, D> {
@@ -33,3 +72,8 @@ impl<'a, D> Write for Formatter<'a, D> {
         self.buf.write_str(s)
     }
 }
+
+/// The name of the bind parameter for the element at `index` of an expanded array bind parameter.
+pub fn array_element_name(name: &str, index: usize) -> String {
+    format!("{name}__{index}")
+}
@@ -1,3 +1,5 @@
+use std::fmt::Write;
+
 pub enum Dialect {}
 
 impl crate::Dialect for Dialect {
@@ -8,4 +10,8 @@ impl crate::Dialect for Dialect {
     fn fmt_bind_param(formatter: &mut impl Write, name: &str, _ordinal: u32) -> std::fmt::Result {
         write!(formatter, "@{name}")
     }
+
+    fn upsert_syntax() -> crate::UpsertSyntax {
+        crate::UpsertSyntax::Unsupported
+    }
 }
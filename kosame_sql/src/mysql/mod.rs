@@ -1,3 +1,5 @@
+use std::fmt::Write;
+
 pub enum Dialect {}
 
 impl crate::Dialect for Dialect {
@@ -5,7 +7,20 @@ impl crate::Dialect for Dialect {
         ("`", "`")
     }
 
-    fn fmt_bind_param(formatter: &mut impl Write, name: &str, _ordinal: u32) -> std::fmt::Result {
-        write!(formatter, ":{name}")
+    fn fmt_bind_param(formatter: &mut impl Write, _name: &str, _ordinal: u32) -> std::fmt::Result {
+        formatter.write_str("?")
+    }
+
+    fn upsert_syntax() -> crate::UpsertSyntax {
+        crate::UpsertSyntax::OnDuplicateKeyUpdate
+    }
+
+    /// `||` is a logical or in MySQL.
+    fn supports_concat_operator() -> bool {
+        false
+    }
+
+    fn fmt_default_values(formatter: &mut impl Write) -> std::fmt::Result {
+        formatter.write_str(" values ()")
     }
 }
@@ -10,4 +10,12 @@ impl crate::Dialect for Dialect {
     fn fmt_bind_param(formatter: &mut impl Write, _name: &str, ordinal: u32) -> std::fmt::Result {
         write!(formatter, "${}", ordinal + 1)
     }
+
+    fn upsert_syntax() -> crate::UpsertSyntax {
+        crate::UpsertSyntax::OnConflict
+    }
+
+    fn supports_arrays() -> bool {
+        true
+    }
 }